@@ -0,0 +1,78 @@
+// generates a test for every answer recorded in answers.toml, for tests/answers.rs, and for
+// every example in examples/, for tests/examples.rs.
+use std::fmt::Write;
+use std::path::Path;
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).to_path_buf();
+    std::fs::write(out.join("answer_tests.rs"), answer_tests(&root)).unwrap();
+    std::fs::write(out.join("example_tests.rs"), example_tests(&root)).unwrap();
+}
+
+fn answer_tests(root: &Path) -> String {
+    let path = root.join("answers.toml");
+    println!("cargo:rerun-if-changed={}", path.display());
+    let text = std::fs::read_to_string(&path).unwrap_or_default();
+    let answers: toml::Table = toml::from_str(&text).expect("answers.toml should be valid toml");
+
+    let mut tests = String::new();
+    for (day, inputs) in &answers {
+        let number = day_number(day).unwrap_or_else(|| panic!("{day} in answers.toml isnt a day"));
+        for (hash, parts) in inputs.as_table().expect("each input should be a table") {
+            for (part, answer) in parts.as_table().expect("each input should have parts") {
+                let n = part.strip_prefix("part").unwrap_or(part);
+                let answer = answer.as_str().expect("answers should be strings");
+                writeln!(
+                    tests,
+                    "#[test]\nfn {day}_{part}_{hash}() {{\n    check({number}, {n}, {hash:?}, {answer:?});\n}}\n"
+                )
+                .unwrap();
+            }
+        }
+    }
+    tests
+}
+
+fn example_tests(root: &Path) -> String {
+    let examples = root.join("examples");
+    // a directory is rerun on if anything in it changes, so new examples get picked up too.
+    println!("cargo:rerun-if-changed={}", examples.display());
+    let mut files = Vec::new();
+    for dir in std::fs::read_dir(&examples).into_iter().flatten().flatten() {
+        let day = dir.file_name().to_string_lossy().to_string();
+        let Some(number) = day_number(&day) else {
+            continue;
+        };
+        for file in std::fs::read_dir(dir.path())
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            let path = file.path();
+            if path.extension().is_some_and(|e| e == "txt") {
+                let name = path.file_stem().unwrap().to_string_lossy().to_string();
+                files.push((day.clone(), number, name));
+            }
+        }
+    }
+    files.sort();
+
+    let mut tests = String::new();
+    for (day, number, name) in files {
+        let ident: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        writeln!(
+            tests,
+            "#[test]\nfn {day}_{ident}() {{\n    check({number}, {name:?});\n}}\n"
+        )
+        .unwrap();
+    }
+    tests
+}
+
+fn day_number(day: &str) -> Option<u8> {
+    day.strip_prefix("day").and_then(|n| n.parse().ok())
+}
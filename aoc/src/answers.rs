@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use aoc_libs::day::Part;
+use serde::{Deserialize, Serialize};
+
+/// the confirmed answers for every day and input, as kept in answers.toml. Inputs are told apart
+/// by their hash, so the file can hold answers for more than one account without the inputs
+/// themselves.
+///
+/// ```toml
+/// [day07.2dd6ec89c95e88b0]
+/// part1 = "250232501"
+/// part2 = "249138943"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, Recorded>>,
+}
+
+/// the answers recorded for one input.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recorded {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Recorded {
+    fn part(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// how a fresh answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Matches,
+    Changed { expected: String, got: String },
+    Unrecorded { got: String },
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Matches => write!(f, "ok"),
+            Check::Changed { expected, got } => {
+                write!(f, "changed\n  expected: {expected}\n  got:      {got}")
+            }
+            Check::Unrecorded { got } => write!(f, "not recorded yet, got {got}"),
+        }
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+impl Answers {
+    /// reads the answers file. A missing file just has no answers in it yet.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(e) => return Err(format!("couldnt read {}: {e}", path.display())),
+        };
+        toml::from_str(&text).map_err(|e| format!("couldnt understand {}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string(self).expect("answers are always valid toml");
+        std::fs::write(path, text).map_err(|e| format!("couldnt write {}: {e}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, input_hash: &str, part: Part) -> Option<&str> {
+        let recorded = self.days.get(&day_key(day))?.get(input_hash)?;
+        match part {
+            Part::One => recorded.part1.as_deref(),
+            Part::Two => recorded.part2.as_deref(),
+        }
+    }
+
+    /// records answer, giving back the answer it replaced if there was one.
+    pub fn set(&mut self, day: u8, input_hash: &str, part: Part, answer: &str) -> Option<String> {
+        let recorded = self
+            .days
+            .entry(day_key(day))
+            .or_default()
+            .entry(input_hash.to_string())
+            .or_default();
+        recorded.part(part).replace(answer.to_string())
+    }
+
+    pub fn check(&self, day: u8, input_hash: &str, part: Part, got: &str) -> Check {
+        match self.get(day, input_hash, part) {
+            Some(expected) if expected == got => Check::Matches,
+            Some(expected) => Check::Changed {
+                expected: expected.to_string(),
+                got: got.to_string(),
+            },
+            None => Check::Unrecorded {
+                got: got.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_check() {
+        let mut answers = Answers::default();
+        assert_eq!(answers.set(7, "abc", Part::Two, "5905"), None);
+        assert_eq!(answers.get(7, "abc", Part::Two), Some("5905"));
+        assert_eq!(answers.get(7, "abc", Part::One), None);
+        assert_eq!(answers.get(7, "def", Part::Two), None);
+        assert_eq!(answers.check(7, "abc", Part::Two, "5905"), Check::Matches);
+        assert_eq!(
+            answers.check(7, "abc", Part::Two, "6440").to_string(),
+            "changed\n  expected: 5905\n  got:      6440"
+        );
+        assert_eq!(
+            answers.check(7, "abc", Part::One, "6440"),
+            Check::Unrecorded {
+                got: "6440".to_string()
+            }
+        );
+        assert_eq!(
+            answers.set(7, "abc", Part::Two, "6440"),
+            Some("5905".to_string())
+        );
+    }
+
+    #[test]
+    fn test_toml() {
+        let text = concat!(
+            "[day07.2dd6ec89c95e88b0]\n",
+            "part1 = \"250232501\"\n",
+            "part2 = \"249138943\"\n",
+            "\n",
+            "[day11.0123456789abcdef]\n",
+            "part1 = \"374\"\n",
+        );
+        let answers: Answers = toml::from_str(text).unwrap();
+        assert_eq!(
+            answers.get(7, "2dd6ec89c95e88b0", Part::Two),
+            Some("249138943")
+        );
+        assert_eq!(answers.get(11, "0123456789abcdef", Part::Two), None);
+        assert_eq!(toml::to_string(&answers).unwrap(), text);
+    }
+}
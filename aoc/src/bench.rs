@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use aoc_libs::day::Part;
+use aoc_libs::solution::Run;
+
+/// summary statistics of how long something took over several runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// the statistics of samples, which cant be empty.
+    pub fn of(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = sorted
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Stats {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// the time every run spent in each phase: parsing, each part that was run, then the total.
+pub fn phases(runs: &[Run]) -> Vec<(String, Vec<Duration>)> {
+    let mut phases = vec![("parse".to_string(), runs.iter().map(|r| r.parse).collect())];
+    for (i, part) in runs[0].parts.iter().enumerate() {
+        let name = part.part.to_string().to_lowercase();
+        phases.push((name, runs.iter().map(|r| r.parts[i].time).collect()));
+    }
+    phases.push(("total".to_string(), runs.iter().map(Run::total).collect()));
+    phases
+}
+
+/// a duration to a couple of decimal places, in whatever unit suits it.
+fn show(d: Duration) -> String {
+    format!("{d:.2?}")
+}
+
+/// how long each phase of a single run took.
+pub fn print_times(run: &Run) {
+    for (name, times) in phases(std::slice::from_ref(run)) {
+        println!("{name:<10} {:>12}", show(times[0]));
+    }
+}
+
+/// the statistics of each phase over many runs.
+pub fn print_stats(runs: &[Run]) {
+    println!("{} runs", runs.len());
+    println!(
+        "{:<10} {:>12} {:>12} {:>12} {:>12}",
+        "phase", "min", "median", "mean", "stddev"
+    );
+    for (name, times) in phases(runs) {
+        let stats = Stats::of(&times);
+        println!(
+            "{name:<10} {:>12} {:>12} {:>12} {:>12}",
+            show(stats.min),
+            show(stats.median),
+            show(stats.mean),
+            show(stats.stddev)
+        );
+    }
+}
+
+/// one row per day, with the median time of each phase, for comparing days at a glance.
+pub fn print_summary(days: &[(u8, Vec<Run>)]) {
+    println!(
+        "{:<6} {:>12} {:>12} {:>12} {:>12}",
+        "day", "parse", "part one", "part two", "total"
+    );
+    let mut total = Duration::ZERO;
+    for (number, runs) in days {
+        let medians: Vec<Duration> = phases(runs)
+            .iter()
+            .map(|(_, times)| Stats::of(times).median)
+            .collect();
+        // phases only has the parts that were run, so line the rest up as blanks.
+        let (parse, day_total) = (medians[0], medians[medians.len() - 1]);
+        let part = |part: Part| {
+            runs[0]
+                .parts
+                .iter()
+                .position(|p| p.part == part)
+                .map_or(String::new(), |i| show(medians[i + 1]))
+        };
+        println!(
+            "{:<6} {:>12} {:>12} {:>12} {:>12}",
+            format!("day{number:02}"),
+            show(parse),
+            part(Part::One),
+            part(Part::Two),
+            show(day_total)
+        );
+        total += day_total;
+    }
+    println!("{:<6} {:>51}", "all", show(total));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_libs::solution::{Answer, PartRun};
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::of(&[ms(4), ms(1), ms(3), ms(2), ms(10)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.mean, ms(4));
+        // the variance is (0 + 9 + 1 + 4 + 36) / 5 = 10ms^2
+        assert_eq!(stats.stddev.as_micros(), 3162);
+        assert_eq!(
+            Stats::of(&[ms(1), ms(2)]).median,
+            Duration::from_micros(1500)
+        );
+        assert_eq!(Stats::of(&[ms(7)]).stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_phases() {
+        let run = |parse, part| Run {
+            parse: ms(parse),
+            parts: vec![PartRun {
+                part: Part::Two,
+                answer: Answer::Int(1),
+                time: ms(part),
+            }],
+        };
+        assert_eq!(
+            phases(&[run(1, 5), run(2, 6)]),
+            vec![
+                ("parse".to_string(), vec![ms(1), ms(2)]),
+                ("part two".to_string(), vec![ms(5), ms(6)]),
+                ("total".to_string(), vec![ms(6), ms(8)]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_libs::day::Day;
+
+/// every day that has been solved so far, in order. A new day needs adding here, and to the
+/// dependencies in Cargo.toml, before the runner knows about it. aoc new does both.
+pub const DAYS: &[Day] = &[
+    day01::DAY,
+    day02::DAY,
+    day03::DAY,
+    day04::DAY,
+    day05::DAY,
+    day06::DAY,
+    day07::DAY,
+    day08::DAY,
+    day09::DAY,
+    day11::DAY,
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_in_order() {
+        assert!(DAYS.windows(2).all(|w| w[0].number < w[1].number));
+    }
+
+    #[test]
+    fn test_get() {
+        assert!(get(7).is_some());
+        assert!(get(10).is_none());
+    }
+}
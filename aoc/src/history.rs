@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_libs::solution::Run;
+use serde::{Deserialize, Serialize};
+
+use crate::bench::{phases, Stats};
+
+/// the timings from one aoc bench of one day, as kept in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    /// the commit that was benchmarked, with -dirty on the end if there were uncommitted changes.
+    pub commit: String,
+    pub input_hash: String,
+    /// when it was recorded, in seconds since the unix epoch.
+    pub timestamp: u64,
+    pub runs: usize,
+    /// the median time of each phase, in nanoseconds.
+    pub median_ns: BTreeMap<String, u64>,
+}
+
+impl Record {
+    pub fn new(day: u8, commit: &str, input_hash: &str, runs: &[Run]) -> Record {
+        Record {
+            day,
+            commit: commit.to_string(),
+            input_hash: input_hash.to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            runs: runs.len(),
+            median_ns: phases(runs)
+                .into_iter()
+                .map(|(name, times)| (name, Stats::of(&times).median.as_nanos() as u64))
+                .collect(),
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        Duration::from_nanos(self.median_ns.get("total").copied().unwrap_or(0))
+    }
+}
+
+/// every record in the history file, oldest first. A missing file is just an empty history.
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+    let json = match std::fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("couldnt read {}: {e}", path.display())),
+    };
+    serde_json::from_str(&json).map_err(|e| format!("couldnt understand {}: {e}", path.display()))
+}
+
+pub fn save(path: &Path, records: &[Record]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(records).expect("records are always valid json");
+    std::fs::write(path, json + "\n").map_err(|e| format!("couldnt write {}: {e}", path.display()))
+}
+
+/// the commit checked out right now, according to git, or "unknown" outside a git repo.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{commit}-dirty")
+        }
+        Some(commit) => commit,
+        None => "unknown".to_string(),
+    }
+}
+
+/// the record to compare new against: the latest one for the same day and input, from commit
+/// against if thats given. Commits can be shortened, like in git.
+pub fn baseline<'a>(
+    history: &'a [Record],
+    new: &Record,
+    against: Option<&str>,
+) -> Option<&'a Record> {
+    history
+        .iter()
+        .rev()
+        .filter(|r| r.day == new.day && r.input_hash == new.input_hash)
+        .find(|r| against.is_none_or(|commit| r.commit.starts_with(commit)))
+}
+
+/// how much slower new is than old in total, as a percentage. Negative if its faster, and none
+/// if old has no total time to compare to.
+pub fn change(old: &Record, new: &Record) -> Option<f64> {
+    let (old, new) = (old.total().as_secs_f64(), new.total().as_secs_f64());
+    (old > 0.0).then(|| (new - old) / old * 100.0)
+}
+
+/// prints how each new record compares to its baseline in history, and returns how many got
+/// slower by more than threshold percent.
+pub fn print_comparison(
+    history: &[Record],
+    new: &[Record],
+    threshold: f64,
+    against: Option<&str>,
+) -> usize {
+    println!(
+        "{:<6} {:>14} {:>12} {:>12} {:>9}",
+        "day", "baseline", "before", "now", "change"
+    );
+    let mut slower = 0;
+    for record in new {
+        let day = format!("day{:02}", record.day);
+        let Some(old) = baseline(history, record, against) else {
+            println!("{day:<6} no earlier run with this input to compare to");
+            continue;
+        };
+        let Some(change) = change(old, record) else {
+            println!("{day:<6} {:>14} no baseline time to compare to", old.commit);
+            continue;
+        };
+        let flag = if change > threshold {
+            slower += 1;
+            format!("  slower than the {threshold}% threshold")
+        } else {
+            String::new()
+        };
+        println!(
+            "{day:<6} {:>14} {:>12} {:>12} {:>+8.1}%{flag}",
+            old.commit,
+            format!("{:.2?}", old.total()),
+            format!("{:.2?}", record.total()),
+            change
+        );
+    }
+    slower
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, commit: &str, input_hash: &str, total_ns: u64) -> Record {
+        Record {
+            day,
+            commit: commit.to_string(),
+            input_hash: input_hash.to_string(),
+            timestamp: 0,
+            runs: 1,
+            median_ns: BTreeMap::from([("total".to_string(), total_ns)]),
+        }
+    }
+
+    #[test]
+    fn test_baseline() {
+        let history = vec![
+            record(5, "aaaaaaa", "input", 100),
+            record(5, "bbbbbbb", "input", 110),
+            record(5, "ccccccc", "other input", 50),
+            record(6, "ccccccc", "input", 50),
+        ];
+        let new = record(5, "ddddddd", "input", 120);
+        assert_eq!(baseline(&history, &new, None), Some(&history[1]));
+        assert_eq!(baseline(&history, &new, Some("aaa")), Some(&history[0]));
+        assert_eq!(baseline(&history, &new, Some("ccc")), None);
+        assert_eq!(baseline(&history, &record(7, "d", "input", 1), None), None);
+    }
+
+    #[test]
+    fn test_change() {
+        let old = record(5, "a", "input", 200);
+        assert_eq!(change(&old, &record(5, "b", "input", 250)), Some(25.0));
+        assert_eq!(change(&old, &record(5, "b", "input", 100)), Some(-50.0));
+    }
+
+    #[test]
+    fn test_change_without_baseline_time() {
+        let zero = record(5, "a", "input", 0);
+        assert_eq!(change(&zero, &record(5, "b", "input", 250)), None);
+        let mut missing = record(5, "a", "input", 0);
+        missing.median_ns.clear();
+        assert_eq!(change(&missing, &record(5, "b", "input", 250)), None);
+        // so it isnt counted as a regression, or as anything else.
+        let new = [record(5, "b", "input", 250)];
+        assert_eq!(print_comparison(&[zero], &new, 10.0, None), 0);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path =
+            std::env::temp_dir().join(format!("aoc_history_test_{}.json", std::process::id()));
+        assert_eq!(load(&path), Ok(Vec::new()));
+        let records = vec![record(5, "a", "input", 200), record(6, "a", "input", 3)];
+        save(&path, &records).unwrap();
+        assert_eq!(load(&path), Ok(records));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
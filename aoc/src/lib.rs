@@ -0,0 +1,6 @@
+// the runners pieces live in a library, so the tests in tests/ can use them as well.
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod history;
+pub mod scaffold;
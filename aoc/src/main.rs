@@ -0,0 +1,421 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc::answers::{Answers, Check};
+use aoc::{bench, days, history, scaffold};
+use aoc_libs::day::{Day, Part};
+use aoc_libs::error::AocError;
+use aoc_libs::solution::Run;
+use aoc_libs::{example, input};
+use clap::{Parser, Subcommand};
+
+/// runs any of the solved days, so you dont need to remember cargo run -p dayNN.
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// solve a day, or every day with --all.
+    Run {
+        /// the day to run.
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        /// run every day.
+        #[arg(long)]
+        all: bool,
+        /// only run this part.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// read the input from this file, or - for stdin. Defaults to inputs/dayNN.txt.
+        #[arg(long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// solve examples/dayNN/NAME.txt instead, and check it gives the answers in it.
+        #[arg(
+            long,
+            value_name = "NAME",
+            num_args = 0..=1,
+            default_missing_value = "example",
+            conflicts_with_all = ["all", "input", "time", "bench"]
+        )]
+        example: Option<String>,
+        /// show how long parsing and each part took.
+        #[arg(long)]
+        time: bool,
+        /// solve each day N times, and show statistics of how long each phase took.
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        bench: Option<u32>,
+    },
+    /// benchmark a day, or every day if none is given, and add the results to the history.
+    Bench {
+        day: Option<u8>,
+        /// how many times to solve each day.
+        #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// compare against the last benchmark of each day with the same input, and fail if any
+        /// got slower than the threshold.
+        #[arg(long)]
+        compare: bool,
+        /// how much slower, in percent, counts as a regression.
+        #[arg(long, default_value_t = 10.0, requires = "compare")]
+        threshold: f64,
+        /// compare against the last benchmark from this commit instead.
+        #[arg(long, value_name = "COMMIT", requires = "compare")]
+        against: Option<String>,
+        /// where the history is kept.
+        #[arg(long, default_value = "bench_history.json")]
+        history: PathBuf,
+    },
+    /// check the answers for each days input against the ones recorded in the answers file.
+    Verify {
+        /// only check this day.
+        day: Option<u8>,
+        /// read the input from this file, or - for stdin. Defaults to inputs/dayNN.txt.
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// record a days answers for its input in the answers file, once theyre confirmed right.
+    Record {
+        day: u8,
+        /// only record this part.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// read the input from this file, or - for stdin. Defaults to inputs/dayNN.txt.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// start a new day from the template, and add it to the runner.
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// list the days that can be run.
+    List,
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run {
+            day,
+            all,
+            part,
+            input,
+            example,
+            time,
+            bench,
+        } => {
+            if let (Some(day), Some(name)) = (day, example) {
+                return run_example(day, &name, part.and_then(Part::from_number));
+            }
+            let parts = match part.and_then(Part::from_number) {
+                Some(part) => vec![part],
+                None => Part::BOTH.to_vec(),
+            };
+            let numbers = if all {
+                days::DAYS.iter().map(|day| day.number).collect()
+            } else {
+                day.into_iter().collect::<Vec<_>>()
+            };
+            let mut failed = false;
+            let mut timings = Vec::new();
+            for number in numbers {
+                let Some((_, runs)) = solve(number, &parts, input.as_deref(), bench.unwrap_or(1))
+                else {
+                    failed = true;
+                    continue;
+                };
+                print_answers(number, &runs[0]);
+                // with --all the times all go in one table at the end instead.
+                if !all && bench.is_some() {
+                    bench::print_stats(&runs);
+                } else if !all && time {
+                    bench::print_times(&runs[0]);
+                }
+                timings.push((number, runs));
+            }
+            if all && (time || bench.is_some()) {
+                bench::print_summary(&timings);
+            }
+            if failed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::Bench {
+            day,
+            runs,
+            compare,
+            threshold,
+            against,
+            history,
+        } => {
+            let numbers = match day {
+                Some(day) => vec![day],
+                None => days::DAYS.iter().map(|day| day.number).collect(),
+            };
+            let commit = history::current_commit();
+            let mut failed = false;
+            let mut timings = Vec::new();
+            let mut records = Vec::new();
+            for number in numbers {
+                let Some((input, runs)) = solve(number, &Part::BOTH, None, runs) else {
+                    failed = true;
+                    continue;
+                };
+                records.push(history::Record::new(
+                    number,
+                    &commit,
+                    &input::hash(&input),
+                    &runs,
+                ));
+                timings.push((number, runs));
+            }
+            bench::print_summary(&timings);
+
+            let result = history::load(&history).and_then(|mut old| {
+                if compare {
+                    println!();
+                    let slower =
+                        history::print_comparison(&old, &records, threshold, against.as_deref());
+                    failed |= slower > 0;
+                }
+                old.extend(records);
+                history::save(&history, &old)
+            });
+            if let Err(e) = result {
+                eprintln!("{e}");
+                failed = true;
+            }
+            if failed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::Verify {
+            day,
+            input,
+            answers,
+        } => {
+            let answers = match Answers::load(&answers) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let numbers = match day {
+                Some(day) => vec![day],
+                None => days::DAYS.iter().map(|day| day.number).collect(),
+            };
+            let (mut failed, mut ok, mut changed, mut unrecorded) = (false, 0, 0, 0);
+            for number in numbers {
+                let Some((input, runs)) = solve(number, &Part::BOTH, input.as_deref(), 1) else {
+                    failed = true;
+                    continue;
+                };
+                let hash = input::hash(&input);
+                for part in &runs[0].parts {
+                    let check = answers.check(number, &hash, part.part, &part.answer.to_string());
+                    println!("day{number:02} part {}: {check}", part.part.number());
+                    match check {
+                        Check::Matches => ok += 1,
+                        Check::Changed { .. } => changed += 1,
+                        Check::Unrecorded { .. } => unrecorded += 1,
+                    }
+                }
+            }
+            println!("{ok} ok, {changed} changed, {unrecorded} not recorded");
+            if unrecorded > 0 {
+                println!("once an answer is confirmed, save it with aoc record <day>");
+            }
+            if failed || changed > 0 {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::Record {
+            day,
+            part,
+            input,
+            answers: path,
+        } => {
+            let parts = match part.and_then(Part::from_number) {
+                Some(part) => vec![part],
+                None => Part::BOTH.to_vec(),
+            };
+            let Some((input, runs)) = solve(day, &parts, input.as_deref(), 1) else {
+                return ExitCode::FAILURE;
+            };
+            let result = Answers::load(&path).and_then(|mut answers| {
+                let hash = input::hash(&input);
+                for part in &runs[0].parts {
+                    let answer = part.answer.to_string();
+                    let n = part.part.number();
+                    match answers.set(day, &hash, part.part, &answer) {
+                        Some(old) if old == answer => println!("day{day:02} part {n}: {answer}"),
+                        Some(old) => {
+                            println!("day{day:02} part {n}: {answer}, replacing {old}")
+                        }
+                        None => println!("day{day:02} part {n}: {answer}, newly recorded"),
+                    }
+                }
+                answers.save(&path)
+            });
+            match result {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::New { day } => match scaffold::new_day(Path::new(""), day) {
+            Ok(made) => {
+                for path in made {
+                    println!("{}", path.display());
+                }
+                println!("save your input in inputs/day{day:02}.txt and the example in examples/day{day:02}/example.txt");
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::List => {
+            for day in days::DAYS {
+                println!("day{:02}", day.number);
+            }
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+/// loads the input for a day and solves the given parts of it times times, giving back the input
+/// and how long each run took. Prints the error and returns none if it couldnt be solved.
+fn solve(
+    number: u8,
+    parts: &[Part],
+    path: Option<&Path>,
+    times: u32,
+) -> Option<(String, Vec<Run>)> {
+    let day = find(number)?;
+    let solved = input::load(number, path).and_then(|input| {
+        let runs = repeat(day, &input, parts, times)?;
+        Ok((input, runs))
+    });
+    match solved {
+        Ok(solved) => Some(solved),
+        Err(e) => {
+            eprintln!("{e}");
+            None
+        }
+    }
+}
+
+/// solves the example called name for a day, and checks it gives the answers the example has.
+fn run_example(number: u8, name: &str, part: Option<Part>) -> ExitCode {
+    let Some(day) = find(number) else {
+        return ExitCode::FAILURE;
+    };
+    let path = example::path(number, name);
+    let solved = example::read(&path).and_then(|example| {
+        // only run the parts the example has answers for, since the other might not even parse,
+        // like day 1s part 2 example in part 1.
+        let parts = match part {
+            Some(part) => vec![part],
+            None => example.parts(),
+        };
+        Ok((day.run(&example.input, &parts)?, example))
+    });
+    let (run, example) = match solved {
+        Ok(solved) => solved,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    print_answers(number, &run);
+    let mut wrong = false;
+    for part in &run.parts {
+        match example.answer(part.part) {
+            Some(expected) if expected == part.answer.to_string() => {}
+            Some(expected) => {
+                println!("{} is wrong, the example expects {expected}", part.part);
+                wrong = true;
+            }
+            None => println!("{} has no answer in {}", part.part, path.display()),
+        }
+    }
+    if wrong {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn find(number: u8) -> Option<&'static Day> {
+    let day = days::get(number);
+    if day.is_none() {
+        eprintln!("day {number} hasnt been solved yet, see aoc list for the ones that have");
+    }
+    day
+}
+
+fn repeat(day: &Day, input: &str, parts: &[Part], times: u32) -> Result<Vec<Run>, AocError> {
+    (0..times).map(|_| day.run(input, parts)).collect()
+}
+
+fn print_answers(number: u8, run: &Run) {
+    println!("Day {number}");
+    for part in &run.parts {
+        println!("{}", part.part);
+        println!("Result: {}", part.answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli() {
+        assert!(Cli::try_parse_from(["aoc", "run", "7", "--part", "2"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "run", "--all"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "list"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "run"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "7", "--all"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "7", "--part", "3"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "7", "--input", "-"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "run", "--all", "--input", "x.txt"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "5", "--time"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "run", "--all", "--bench", "10"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "run", "5", "--bench", "0"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "1", "--example"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "run", "1", "--example", "part2"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "run", "--all", "--example"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "1", "--example", "--input", "-"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "bench"]).is_ok());
+        assert!(
+            Cli::try_parse_from(["aoc", "bench", "5", "--compare", "--threshold", "5"]).is_ok()
+        );
+        assert!(Cli::try_parse_from(["aoc", "bench", "--threshold", "5"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "verify"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "verify", "--input", "x.txt"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "record", "7", "--part", "2"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "record"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "new", "12"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "new", "26"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "new"]).is_err());
+    }
+}
@@ -0,0 +1,323 @@
+use std::path::{Path, PathBuf};
+
+/// starts a new day in the workspace at root: copies template/ to days/dayNN with its names
+/// filled in, adds it to the runner, and makes an empty input and example for it. Nothing is
+/// written if the day already exists. Gives back every file it made.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day{day:02}");
+    let dir = root.join("days").join(&name);
+    if dir.exists() {
+        return Err(format!(
+            "{} already exists, not overwriting it",
+            dir.display()
+        ));
+    }
+    let template = root.join("template");
+    if !template.is_dir() {
+        return Err(format!(
+            "couldnt find {}, run aoc new from the root of the workspace",
+            template.display()
+        ));
+    }
+
+    // work everything out before writing anything, so a failure doesnt leave half a day behind.
+    let mut files = Vec::new();
+    let mut has_placeholder = false;
+    for path in template_files(&template)? {
+        let text = read(&path)?;
+        has_placeholder |= text.contains(PLACEHOLDER);
+        let relative = path.strip_prefix(&template).unwrap();
+        let relative = relative.to_string_lossy().replace("template", &name);
+        files.push((dir.join(relative), fill(&text, day)));
+    }
+    if !has_placeholder {
+        return Err(format!(
+            "the template doesnt say where the days number goes, it needs a {PLACEHOLDER}"
+        ));
+    }
+    let cargo = root.join("aoc/Cargo.toml");
+    files.push((cargo.clone(), register_dependency(&read(&cargo)?, day)?));
+    let days = root.join("aoc/src/days.rs");
+    files.push((days.clone(), register_day(&read(&days)?, day)?));
+    // an input or example might already be saved, so those are left alone if they are.
+    let input = root.join(aoc_libs::input::default_path(day));
+    if !input.exists() {
+        files.push((input, String::new()));
+    }
+    let example = root.join(aoc_libs::example::path(day, "example"));
+    if !example.exists() {
+        files.push((example, "part1:\npart2:\n---\n".to_string()));
+    }
+
+    for (path, text) in &files {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("couldnt create {}: {e}", parent.display()))?;
+        }
+        std::fs::write(path, text).map_err(|e| format!("couldnt write {}: {e}", path.display()))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// every file in the template, apart from anything cargo built there.
+fn template_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| format!("couldnt read {}: {e}", dir.display()))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("couldnt read {}: {e}", dir.display()))?
+            .path();
+        if path.is_dir() {
+            if path.file_name().is_some_and(|n| n != "target") {
+                files.extend(template_files(&path)?);
+            }
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("couldnt read {}: {e}", path.display()))
+}
+
+/// where the template has the days number. The template defines it as a const so it still
+/// builds, and that definition is left out of new days.
+const PLACEHOLDER: &str = "__DAY__";
+
+/// a template file with the names and day number filled in. Only the crate name and the
+/// Template type are renamed, so anything else that happens to say template is left alone.
+fn fill(text: &str, day: u8) -> String {
+    let definition = format!("const {PLACEHOLDER}");
+    let mut lines: Vec<&str> = Vec::new();
+    for line in text.split_inclusive('\n') {
+        if line.trim_start().starts_with(&definition) {
+            // along with the blank line that separated it from whatever came before.
+            if lines.last().is_some_and(|l| l.trim().is_empty()) {
+                lines.pop();
+            }
+        } else {
+            lines.push(line);
+        }
+    }
+    let filled = lines.concat().replace(PLACEHOLDER, &day.to_string());
+    let filled = replace_word(&filled, "Template", &format!("Day{day:02}"));
+    let filled = replace_word(&filled, "template::", &format!("day{day:02}::"));
+    replace_word(&filled, "\"template\"", &format!("\"day{day:02}\""))
+}
+
+/// text with every from replaced, apart from where its only part of a longer name.
+fn replace_word(text: &str, from: &str, to: &str) -> String {
+    let is_name = |c: char| c.is_alphanumeric() || c == '_';
+    let mut replaced = String::new();
+    let mut rest = 0;
+    for (at, _) in text.match_indices(from) {
+        let before = text[..at].chars().next_back();
+        let after = text[at + from.len()..].chars().next();
+        let joined = (from.starts_with(is_name) && before.is_some_and(is_name))
+            || (from.ends_with(is_name) && after.is_some_and(is_name));
+        if !joined {
+            replaced.push_str(&text[rest..at]);
+            replaced.push_str(to);
+            rest = at + from.len();
+        }
+    }
+    replaced.push_str(&text[rest..]);
+    replaced
+}
+
+/// the runners Cargo.toml with day added to its dependencies, in order with the other days.
+fn register_dependency(cargo: &str, day: u8) -> Result<String, String> {
+    let line = format!("day{day:02} = {{ path = \"../days/day{day:02}\" }}");
+    if cargo.lines().any(|l| l == line) {
+        return Err(format!("day{day:02} is already in aoc/Cargo.toml"));
+    }
+    insert_in_order(cargo, &line, |l| {
+        l.starts_with("day") && l.contains("../days/")
+    })
+    .ok_or_else(|| format!("couldnt find where to add day{day:02} in aoc/Cargo.toml"))
+}
+
+/// days.rs with day added to DAYS, in order with the other days.
+fn register_day(days: &str, day: u8) -> Result<String, String> {
+    let line = format!("    day{day:02}::DAY,");
+    if days.lines().any(|l| l == line) {
+        return Err(format!("day{day:02} is already in aoc/src/days.rs"));
+    }
+    insert_in_order(days, &line, |l| {
+        l.starts_with("    day") && l.ends_with("::DAY,")
+    })
+    .ok_or_else(|| format!("couldnt find where to add day{day:02} in aoc/src/days.rs"))
+}
+
+/// text with line inserted among the lines that is_day, keeping them sorted. None if there
+/// arent any lines like it to go next to.
+fn insert_in_order(text: &str, line: &str, is_day: impl Fn(&str) -> bool) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<usize> = (0..lines.len()).filter(|&i| is_day(lines[i])).collect();
+    if days.is_empty() {
+        return None;
+    }
+    let at = days
+        .iter()
+        .find(|&&i| lines[i] > line)
+        .copied()
+        .unwrap_or(days[days.len() - 1] + 1);
+    lines.insert(at, line);
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill() {
+        assert_eq!(
+            fill(
+                "pub const DAY: Day = Day::new::<Template>(__DAY__);\n\nconst __DAY__: u8 = 0; // a note\n",
+                12
+            ),
+            "pub const DAY: Day = Day::new::<Day12>(12);\n"
+        );
+        assert_eq!(
+            fill(
+                "pub struct Template;\n\nconst __DAY__: u8 = 0;\n\nmod tests {}\n",
+                7
+            ),
+            "pub struct Day07;\n\nmod tests {}\n"
+        );
+        assert_eq!(
+            fill(
+                "use template::{Template, DAY};\nbench_day::<Template>(c, DAY.number);\n",
+                3
+            ),
+            "use day03::{Day03, DAY};\nbench_day::<Day03>(c, DAY.number);\n"
+        );
+        assert_eq!(
+            fill("[package]\nname = \"template\"\n", 3),
+            "[package]\nname = \"day03\"\n"
+        );
+        // the template itself builds as day 0, but nothing else should say so.
+        assert_eq!(fill("Day::new::<Template>(0)", 25), "Day::new::<Day25>(0)");
+    }
+
+    #[test]
+    fn test_fill_only_renames_the_template() {
+        let text = "// the template, copied by aoc new.\nlet templates = TemplateCache::new(my_template::load());\n";
+        assert_eq!(fill(text, 4), text);
+    }
+
+    #[test]
+    fn test_register_day() {
+        let days = "pub const DAYS: &[Day] = &[\n    day01::DAY,\n    day11::DAY,\n];\n";
+        assert_eq!(
+            register_day(days, 7).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    day01::DAY,\n    day07::DAY,\n    day11::DAY,\n];\n"
+        );
+        assert_eq!(
+            register_day(days, 12).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    day01::DAY,\n    day11::DAY,\n    day12::DAY,\n];\n"
+        );
+        assert_eq!(
+            register_day(days, 11),
+            Err("day11 is already in aoc/src/days.rs".to_string())
+        );
+        assert!(register_day("pub const DAYS: &[Day] = &[];\n", 1).is_err());
+    }
+
+    #[test]
+    fn test_register_dependency() {
+        let cargo =
+            "[dependencies]\naoc_libs.workspace = true\nday01 = { path = \"../days/day01\" }\n";
+        assert_eq!(
+            register_dependency(cargo, 2).unwrap(),
+            cargo.to_string() + "day02 = { path = \"../days/day02\" }\n"
+        );
+    }
+
+    /// a directory that gets removed when its dropped, even if the test fails.
+    struct TempDir(PathBuf);
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn write(path: &Path, text: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, text).unwrap();
+    }
+
+    /// a workspace with the real template in it, and a runner that knows days 1 and 11.
+    fn workspace(name: &str) -> TempDir {
+        let root = TempDir(
+            std::env::temp_dir().join(format!("aoc_scaffold_{name}_{}", std::process::id())),
+        );
+        let real = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for file in template_files(&real.join("template")).unwrap() {
+            let text = std::fs::read_to_string(&file).unwrap();
+            write(&root.0.join(file.strip_prefix(&real).unwrap()), &text);
+        }
+        write(
+            &root.0.join("aoc/Cargo.toml"),
+            "[dependencies]\nday01 = { path = \"../days/day01\" }\nday11 = { path = \"../days/day11\" }\n",
+        );
+        write(
+            &root.0.join("aoc/src/days.rs"),
+            "pub const DAYS: &[Day] = &[\n    day01::DAY,\n    day11::DAY,\n];\n",
+        );
+        root
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = workspace("new_day");
+        let made = new_day(&root.0, 12).unwrap();
+        assert!(made.contains(&root.0.join("days/day12/benches/day12.rs")));
+        assert!(made.contains(&root.0.join("inputs/day12.txt")));
+        let lib = std::fs::read_to_string(root.0.join("days/day12/src/lib.rs")).unwrap();
+        assert!(lib.contains("Day::new::<Day12>(12)"));
+        assert!(!lib.contains("emplate"));
+        assert!(!lib.contains(PLACEHOLDER));
+        assert!(!lib.contains("\n\n\n"));
+        let cargo = std::fs::read_to_string(root.0.join("days/day12/Cargo.toml")).unwrap();
+        assert!(cargo.contains("name = \"day12\""));
+        let days = std::fs::read_to_string(root.0.join("aoc/src/days.rs")).unwrap();
+        assert!(days.contains("day11::DAY,\n    day12::DAY,"));
+        let cargo = std::fs::read_to_string(root.0.join("aoc/Cargo.toml")).unwrap();
+        assert!(cargo.ends_with("day12 = { path = \"../days/day12\" }\n"));
+
+        assert_eq!(
+            new_day(&root.0, 12),
+            Err(format!(
+                "{} already exists, not overwriting it",
+                root.0.join("days/day12").display()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_new_day_keeps_saved_input() {
+        let root = workspace("saved_input");
+        write(&root.0.join("inputs/day05.txt"), "seeds: 1 2\n");
+        let made = new_day(&root.0, 5).unwrap();
+        assert!(!made.contains(&root.0.join("inputs/day05.txt")));
+        let input = std::fs::read_to_string(root.0.join("inputs/day05.txt")).unwrap();
+        assert_eq!(input, "seeds: 1 2\n");
+    }
+
+    #[test]
+    fn test_new_day_without_placeholder() {
+        let root = workspace("no_placeholder");
+        let lib = root.0.join("template/src/lib.rs");
+        let text = std::fs::read_to_string(&lib).unwrap();
+        write(&lib, &text.replace(PLACEHOLDER, "0"));
+        assert!(new_day(&root.0, 12).is_err());
+        assert!(!root.0.join("days/day12").exists());
+    }
+}
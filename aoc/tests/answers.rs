@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use aoc::days;
+use aoc_libs::day::Part;
+use aoc_libs::input;
+
+/// checks a day gives the recorded answer for the input with hash. Inputs arent always there (or
+/// might be someone elses), so the test is skipped with a note if the saved input doesnt match.
+fn check(day: u8, part: u8, hash: &str, expected: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(input::default_path(day));
+    let input = match input::load(day, Some(&path)) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("skipping: {e}");
+            return;
+        }
+    };
+    if input::hash(&input) != hash {
+        eprintln!(
+            "skipping: the input in {} isnt the one recorded",
+            path.display()
+        );
+        return;
+    }
+    let part = Part::from_number(part).unwrap();
+    let got = days::get(day)
+        .unwrap_or_else(|| panic!("day{day:02} isnt in aoc"))
+        .solve(part, &input)
+        .unwrap()
+        .to_string();
+    assert!(
+        got == expected,
+        "day{day:02} {part} changed from answers.toml\n  expected: {expected}\n  got:      {got}"
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
@@ -0,0 +1,23 @@
+use aoc::days;
+use aoc_libs::example;
+
+/// checks a day gives every answer the example called name has. Examples with no answers yet,
+/// like the blank one a new day starts with, are skipped with a note.
+fn check(day: u8, name: &str) {
+    let example = example::load(day, name).unwrap();
+    if example.parts().is_empty() {
+        eprintln!("skipping: day{day:02} {name} doesnt have any answers to check yet");
+        return;
+    }
+    let solution = days::get(day).unwrap_or_else(|| panic!("day{day:02} isnt in aoc"));
+    for part in example.parts() {
+        let expected = example.answer(part).unwrap();
+        let got = solution.solve(part, &example.input).unwrap().to_string();
+        assert!(
+            got == expected,
+            "day{day:02} {name} {part} is wrong\n  expected: {expected}\n  got:      {got}"
+        );
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
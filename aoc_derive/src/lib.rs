@@ -0,0 +1,143 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitChar, Token};
+
+/// derives the mapping between an enum and the characters that stand for it in puzzle input.
+/// Every variant has to be a unit variant with a `#[ch('x')]` attribute giving its character.
+/// Generates:
+/// - `TryFrom<char>`, which gives back the char as the error if no variant uses it.
+/// - `From<Enum> for char`, so variants can be turned back into text with `.into()`.
+/// - `Display`, which writes the variant's char.
+/// - `Enum::VARIANTS`, every variant in declaration order, and `Enum::CHARS`, all their chars
+///   in the same order, which is handy for error messages.
+///
+/// A variant that should be written as a char but never parsed from one (like a joker that is
+/// only ever made from a jack) can be marked `#[ch('J', no_parse)]`. Otherwise every char has to
+/// be different.
+#[proc_macro_derive(CharEnum, attributes(ch))]
+pub fn derive_char_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match char_enum(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+struct Variant {
+    ident: Ident,
+    ch: LitChar,
+    no_parse: bool,
+}
+
+fn char_enum(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "CharEnum can only be derived for enums",
+        ));
+    };
+    let mut variants: Vec<Variant> = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "CharEnum variants cant have fields",
+            ));
+        }
+        let mut attrs = variant.attrs.iter().filter(|a| a.path().is_ident("ch"));
+        let Some(attr) = attrs.next() else {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "CharEnum variants need a #[ch('x')] attribute",
+            ));
+        };
+        if let Some(extra) = attrs.next() {
+            return Err(syn::Error::new_spanned(
+                extra,
+                "only one #[ch] attribute is allowed per variant",
+            ));
+        }
+        let (ch, no_parse) = attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let ch: LitChar = input.parse()?;
+            let mut no_parse = false;
+            if input.parse::<Option<Token![,]>>()?.is_some() {
+                let flag: Ident = input.parse()?;
+                if flag != "no_parse" {
+                    return Err(syn::Error::new_spanned(flag, "expected no_parse"));
+                }
+                no_parse = true;
+            }
+            Ok((ch, no_parse))
+        })?;
+        if !no_parse {
+            if let Some(other) = variants
+                .iter()
+                .find(|v| !v.no_parse && v.ch.value() == ch.value())
+            {
+                return Err(syn::Error::new_spanned(
+                    &ch,
+                    format!("{:?} is already used by {}", ch.value(), other.ident),
+                ));
+            }
+        }
+        variants.push(Variant {
+            ident: variant.ident.clone(),
+            ch,
+            no_parse,
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let parse_arms = variants.iter().filter(|v| !v.no_parse).map(|v| {
+        let (ident, ch) = (&v.ident, &v.ch);
+        quote! { #ch => ::core::result::Result::Ok(#name::#ident), }
+    });
+    let char_arms: Vec<_> = variants
+        .iter()
+        .map(|v| {
+            let (ident, ch) = (&v.ident, &v.ch);
+            quote! { #name::#ident => #ch, }
+        })
+        .collect();
+    let idents = variants.iter().map(|v| &v.ident);
+    let chars: String = variants.iter().map(|v| v.ch.value()).collect();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::TryFrom<char> for #name #ty_generics #where_clause {
+            type Error = char;
+
+            fn try_from(c: char) -> ::core::result::Result<Self, char> {
+                match c {
+                    #(#parse_arms)*
+                    other => ::core::result::Result::Err(other),
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for char #where_clause {
+            fn from(value: #name #ty_generics) -> char {
+                match value {
+                    #(#char_arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let c = match self {
+                    #(#char_arms)*
+                };
+                ::core::fmt::Write::write_char(f, c)
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// every variant, in the order they are declared.
+            pub const VARIANTS: &'static [#name #ty_generics] = &[#(#name::#idents),*];
+            /// the char for each variant, in the same order as VARIANTS.
+            pub const CHARS: &'static str = #chars;
+        }
+    })
+}
@@ -0,0 +1,66 @@
+use aoc_derive::CharEnum;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, CharEnum)]
+enum Tile {
+    #[ch('#')]
+    Wall,
+    #[ch('.')]
+    Open,
+    #[ch('>')]
+    Slope,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, CharEnum)]
+enum Card {
+    #[ch('K')]
+    King = 13,
+    #[ch('J')]
+    Jack = 11,
+    #[ch('J', no_parse)]
+    Joker = 1,
+}
+
+#[test]
+fn test_try_from() {
+    assert_eq!(Tile::try_from('#'), Ok(Tile::Wall));
+    assert_eq!(Tile::try_from('>'), Ok(Tile::Slope));
+    assert_eq!(Tile::try_from('x'), Err('x'));
+}
+
+#[test]
+fn test_into_char() {
+    let c: char = Tile::Open.into();
+    assert_eq!(c, '.');
+    assert_eq!(char::from(Tile::Wall), '#');
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(Tile::Slope.to_string(), ">");
+    let row = [Tile::Wall, Tile::Open, Tile::Open];
+    let text: String = row.iter().map(|t| t.to_string()).collect();
+    assert_eq!(text, "#..");
+}
+
+#[test]
+fn test_variants() {
+    assert_eq!(Tile::VARIANTS, &[Tile::Wall, Tile::Open, Tile::Slope]);
+    assert_eq!(Tile::CHARS, "#.>");
+}
+
+#[test]
+fn test_round_trip() {
+    let text = "#.>.#";
+    let tiles: Vec<Tile> = text.chars().map(|c| c.try_into().unwrap()).collect();
+    let back: String = tiles.into_iter().map(char::from).collect();
+    assert_eq!(back, text);
+}
+
+#[test]
+fn test_no_parse() {
+    assert_eq!(Card::try_from('J'), Ok(Card::Jack));
+    assert_eq!(char::from(Card::Joker), 'J');
+    assert_eq!(Card::Joker.to_string(), "J");
+    assert_eq!(Card::King as u8, 13);
+    assert_eq!(Card::CHARS, "KJJ");
+}
@@ -0,0 +1,19 @@
+use std::hint::black_box;
+
+use aoc_libs::misc::arr_lcm;
+use aoc_libs::parse::normalise;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_arr_lcm(c: &mut Criterion) {
+    // cycle lengths like the ones day08 takes the lcm of.
+    let cycles = [20659, 20093, 14999, 17263, 22357, 16697];
+    c.bench_function("arr_lcm", |b| b.iter(|| arr_lcm(black_box(&cycles))));
+}
+
+fn bench_normalise(c: &mut Criterion) {
+    let input = "0 3 6 9 12 15  \r\n1 3 6 10 15 21\r\n".repeat(100);
+    c.bench_function("normalise", |b| b.iter(|| normalise(black_box(&input))));
+}
+
+criterion_group!(benches, bench_arr_lcm, bench_normalise);
+criterion_main!(benches);
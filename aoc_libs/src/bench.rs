@@ -0,0 +1,56 @@
+use std::hint::black_box;
+use std::path::Path;
+
+use criterion::Criterion;
+
+use crate::solution::Solution;
+use crate::{example, input};
+
+/// benchmarks parsing and each part of S, on both examples/dayNN/example.txt and the real input
+/// for day. Either is skipped with a note if it isnt saved, so benches still run on a fresh
+/// checkout.
+pub fn bench_day<S>(c: &mut Criterion, day: u8)
+where
+    S: Solution,
+{
+    match example::load(day, "example") {
+        Ok(example) => bench_solution::<S>(c, &format!("day{day:02}/example"), &example.input),
+        Err(e) => eprintln!("skipping the example: {e}"),
+    }
+    if let Some(real) = real_input(day) {
+        bench_solution::<S>(c, &format!("day{day:02}/real"), &real);
+    }
+}
+
+/// the real input for day, or none (with a note saying why) if it cant be read.
+pub fn real_input(day: u8) -> Option<String> {
+    // benches run from their own crate, so find inputs/ from the workspace root instead.
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(input::default_path(day));
+    input::load(day, Some(&path))
+        .map_err(|e| eprintln!("skipping the real input: {e}"))
+        .ok()
+}
+
+/// benchmarks parsing and each part of S on input, as a group called name. Parts are timed on
+/// input thats already parsed, so parsing isnt counted twice.
+pub fn bench_solution<S>(c: &mut Criterion, name: &str, input: &str)
+where
+    S: Solution,
+{
+    let parsed = S::parse(input).expect("input should parse");
+    let parsed2 = S::parse_part2(input).map(|p| p.expect("input should parse for part 2"));
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+    if parsed2.is_some() {
+        group.bench_function("parse part2", |b| {
+            b.iter(|| S::parse_part2(black_box(input)))
+        });
+    }
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    let parsed2 = parsed2.as_ref().unwrap_or(&parsed);
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(parsed2))));
+    group.finish();
+}
@@ -0,0 +1,586 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub};
+
+use crate::grid::Grid;
+use crate::points::UPoint;
+
+/// an unsigned integer a BitSet can be built out of.
+pub trait Word:
+    Copy
+    + Eq
+    + Hash
+    + Debug
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+{
+    const BITS: usize;
+    const ZERO: Self;
+    /// just the lowest bit set.
+    const ONE: Self;
+
+    fn count_ones(self) -> u32;
+    fn trailing_zeros(self) -> u32;
+    /// shifts left, so bit i ends up at bit i + by.
+    fn shl(self, by: usize) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($t:ty),*) => {
+        $(
+            impl Word for $t {
+                const BITS: usize = <$t>::BITS as usize;
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn count_ones(self) -> u32 {
+                    <$t>::count_ones(self)
+                }
+
+                fn trailing_zeros(self) -> u32 {
+                    <$t>::trailing_zeros(self)
+                }
+
+                fn shl(self, by: usize) -> Self {
+                    self << by
+                }
+            }
+        )*
+    };
+}
+
+impl_word!(u8, u16, u32, u64, u128);
+
+/// a set of small numbers, stored as N words of bits, so it can hold 0 up to N * W::BITS
+/// (exclusive). Its Copy and never allocates, and set operations are a handful of ands and ors,
+/// which makes it much faster than a HashSet or BTreeSet when the numbers are small.
+/// Inserting a number too big for the set panics.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitSet<const N: usize, W: Word = u64> {
+    words: [W; N],
+}
+
+impl<const N: usize, W: Word> BitSet<{ N }, W> {
+    pub fn new() -> BitSet<{ N }, W> {
+        BitSet {
+            words: [W::ZERO; N],
+        }
+    }
+
+    /// how many numbers the set can hold, ie one more than the largest.
+    pub fn capacity(&self) -> usize {
+        N * W::BITS
+    }
+
+    fn locate(&self, value: usize) -> (usize, W) {
+        assert!(
+            value < self.capacity(),
+            "{} is too big for a BitSet with capacity {}",
+            value,
+            self.capacity()
+        );
+        (value / W::BITS, W::ONE.shl(value % W::BITS))
+    }
+
+    /// adds value to the set, returning whether it wasnt already there.
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (word, bit) = self.locate(value);
+        let was_missing = self.words[word] & bit == W::ZERO;
+        self.words[word] = self.words[word] | bit;
+        was_missing
+    }
+
+    /// removes value from the set, returning whether it was there.
+    pub fn remove(&mut self, value: usize) -> bool {
+        let (word, bit) = self.locate(value);
+        let was_present = self.words[word] & bit != W::ZERO;
+        self.words[word] = self.words[word] & !bit;
+        was_present
+    }
+
+    /// whether value is in the set. Numbers too big for the set are never in it.
+    pub fn contains(&self, value: usize) -> bool {
+        value < self.capacity() && {
+            let (word, bit) = self.locate(value);
+            self.words[word] & bit != W::ZERO
+        }
+    }
+
+    /// the number of values in the set, which is just a popcount.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == W::ZERO)
+    }
+
+    pub fn clear(&mut self) {
+        self.words = [W::ZERO; N];
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        (*self & *other) == *self
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        (*self & *other).is_empty()
+    }
+
+    /// the values in the set, smallest first.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, word)| {
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                if word == W::ZERO {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word = word & !W::ONE.shl(bit);
+                Some(i * W::BITS + bit)
+            })
+        })
+    }
+
+    fn zip_with(self, other: Self, f: impl Fn(W, W) -> W) -> Self {
+        let mut words = self.words;
+        for (word, other) in words.iter_mut().zip(other.words) {
+            *word = f(*word, other);
+        }
+        BitSet { words }
+    }
+}
+
+impl<const N: usize, W: Word> Default for BitSet<{ N }, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, W: Word> Debug for BitSet<{ N }, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<const N: usize, W: Word> FromIterator<usize> for BitSet<{ N }, W> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const N: usize, W: Word> Extend<usize> for BitSet<{ N }, W> {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<const N: usize, W: Word, const M: usize> From<[usize; M]> for BitSet<{ N }, W> {
+    fn from(values: [usize; M]) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+/// intersection.
+impl<const N: usize, W: Word> BitAnd for BitSet<{ N }, W> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a & b)
+    }
+}
+
+/// union.
+impl<const N: usize, W: Word> BitOr for BitSet<{ N }, W> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a | b)
+    }
+}
+
+/// symmetric difference.
+impl<const N: usize, W: Word> BitXor for BitSet<{ N }, W> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a ^ b)
+    }
+}
+
+/// difference.
+impl<const N: usize, W: Word> Sub for BitSet<{ N }, W> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a & !b)
+    }
+}
+
+impl<const N: usize, W: Word> BitAndAssign for BitSet<{ N }, W> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs
+    }
+}
+
+impl<const N: usize, W: Word> BitOrAssign for BitSet<{ N }, W> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs
+    }
+}
+
+impl<const N: usize, W: Word> BitXorAssign for BitSet<{ N }, W> {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs
+    }
+}
+
+/// a grid of booleans packed 64 to a word, one row at a time. Shifting the whole grid one step in
+/// any direction is a few word operations per row, so cellular automaton style puzzles can count
+/// neighbours by shifting and combining whole grids instead of visiting every cell.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// a grid with every cell unset.
+    pub fn new(width: usize, height: usize) -> BitGrid {
+        let words_per_row = width.div_ceil(64);
+        BitGrid {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    fn row_mut(&mut self, y: usize) -> &mut [u64] {
+        &mut self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    /// whether the cell at point is set. Anything outside the grid is unset.
+    pub fn get(&self, point: &UPoint) -> bool {
+        point.x < self.width
+            && point.y < self.height
+            && self.row(point.y)[point.x / 64] & (1 << (point.x % 64)) != 0
+    }
+
+    /// sets or unsets the cell at point. Panics if its outside the grid.
+    pub fn set(&mut self, point: &UPoint, value: bool) {
+        assert!(
+            point.x < self.width && point.y < self.height,
+            "point out of bounds of grid!"
+        );
+        let word = &mut self.row_mut(point.y)[point.x / 64];
+        if value {
+            *word |= 1 << (point.x % 64);
+        } else {
+            *word &= !(1 << (point.x % 64));
+        }
+    }
+
+    /// how many cells are set.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// every set cell, row by row.
+    pub fn points(&self) -> impl Iterator<Item = UPoint> + '_ {
+        (0..self.height).flat_map(move |y| {
+            (0..self.width)
+                .map(move |x| UPoint { x, y })
+                .filter(|p| self.get(p))
+        })
+    }
+
+    /// clears any bits past the right edge of the grid in the last word of each row, so they
+    /// dont get counted or shifted back in.
+    fn mask_padding(&mut self) {
+        if self.width.is_multiple_of(64) || self.words_per_row == 0 {
+            return;
+        }
+        let mask = (1 << (self.width % 64)) - 1;
+        for y in 0..self.height {
+            let last = (y + 1) * self.words_per_row - 1;
+            self.words[last] &= mask;
+        }
+    }
+
+    /// the grid with every cell moved one to the left. The left column falls off, and the right
+    /// column is left unset.
+    pub fn shifted_left(&self) -> BitGrid {
+        let mut shifted = self.clone();
+        for y in 0..self.height {
+            let row = shifted.row_mut(y);
+            for i in 0..row.len() {
+                let carry = row.get(i + 1).map_or(0, |next| next << 63);
+                row[i] = (row[i] >> 1) | carry;
+            }
+        }
+        shifted
+    }
+
+    /// the grid with every cell moved one to the right. The right column falls off, and the left
+    /// column is left unset.
+    pub fn shifted_right(&self) -> BitGrid {
+        let mut shifted = self.clone();
+        for y in 0..self.height {
+            let row = shifted.row_mut(y);
+            for i in (0..row.len()).rev() {
+                let carry = if i > 0 { row[i - 1] >> 63 } else { 0 };
+                row[i] = (row[i] << 1) | carry;
+            }
+        }
+        shifted.mask_padding();
+        shifted
+    }
+
+    /// the grid with every cell moved up a row. The top row falls off, and the bottom row is left
+    /// unset.
+    pub fn shifted_up(&self) -> BitGrid {
+        let mut shifted = BitGrid::new(self.width, self.height);
+        let skip = self.words_per_row.min(self.words.len());
+        shifted.words[..self.words.len() - skip].copy_from_slice(&self.words[skip..]);
+        shifted
+    }
+
+    /// the grid with every cell moved down a row. The bottom row falls off, and the top row is
+    /// left unset.
+    pub fn shifted_down(&self) -> BitGrid {
+        let mut shifted = BitGrid::new(self.width, self.height);
+        let skip = self.words_per_row.min(self.words.len());
+        shifted.words[skip..].copy_from_slice(&self.words[..self.words.len() - skip]);
+        shifted
+    }
+
+    fn zip_with(&self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) -> BitGrid {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "cant combine BitGrids of different sizes"
+        );
+        BitGrid {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+            ..self.clone()
+        }
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = BitGrid::new(grid.width(), grid.height());
+        for point in grid.points().filter(|p| grid[*p]) {
+            bits.set(&point, true);
+        }
+        bits
+    }
+}
+
+/// the cells set in both grids. Panics if the grids are different sizes.
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a & b)
+    }
+}
+
+/// the cells set in either grid. Panics if the grids are different sizes.
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a | b)
+    }
+}
+
+/// the cells set in exactly one of the grids. Panics if the grids are different sizes.
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a ^ b)
+    }
+}
+
+/// every cell flipped.
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> Self::Output {
+        let mut flipped = BitGrid {
+            words: self.words.iter().map(|w| !w).collect(),
+            ..self.clone()
+        };
+        flipped.mask_padding();
+        flipped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set: BitSet<2> = BitSet::new();
+        assert_eq!(set.capacity(), 128);
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert!(set.insert(100));
+        assert!(set.contains(3) && set.contains(100));
+        assert!(!set.contains(4));
+        assert!(!set.contains(1000));
+        assert_eq!(set.len(), 2);
+        assert!(set.remove(3));
+        assert!(!set.remove(3));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![100]);
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_big() {
+        let mut set: BitSet<1, u128> = BitSet::new();
+        set.insert(128);
+    }
+
+    #[test]
+    fn test_set_ops() {
+        let a: BitSet<1, u128> = BitSet::from([1, 5, 64, 99]);
+        let b: BitSet<1, u128> = BitSet::from([5, 99, 127]);
+        assert_eq!((a & b).iter().collect::<Vec<_>>(), vec![5, 99]);
+        assert_eq!((a | b).len(), 5);
+        assert_eq!((a ^ b).iter().collect::<Vec<_>>(), vec![1, 64, 127]);
+        assert_eq!((a - b).iter().collect::<Vec<_>>(), vec![1, 64]);
+        assert!((a & b).is_subset(&a));
+        assert!(!a.is_subset(&b));
+        assert!((a - b).is_disjoint(&b));
+        let mut c = a;
+        c |= b;
+        c &= BitSet::from([1, 127]);
+        assert_eq!(c, BitSet::from([127, 1]));
+        assert_eq!(format!("{:?}", c), "{1, 127}");
+    }
+
+    #[test]
+    fn test_iter_across_words() {
+        let values = vec![0, 7, 8, 15, 16, 31];
+        let set: BitSet<4, u8> = values.iter().copied().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), values);
+    }
+
+    fn grid(rows: &[&str]) -> BitGrid {
+        let rows = rows
+            .iter()
+            .map(|r| r.chars().map(|c| c == '#').collect())
+            .collect();
+        BitGrid::from(&Grid::from_rows(rows).unwrap())
+    }
+
+    #[test]
+    fn test_bit_grid() {
+        let mut bits = grid(&["#..", ".#."]);
+        assert_eq!(bits.count_ones(), 2);
+        assert!(bits.get(&UPoint { x: 1, y: 1 }));
+        assert!(!bits.get(&UPoint { x: 5, y: 1 }));
+        bits.set(&UPoint { x: 2, y: 0 }, true);
+        bits.set(&UPoint { x: 0, y: 0 }, false);
+        assert_eq!(bits, grid(&["..#", ".#."]));
+        assert_eq!(
+            bits.points().collect::<Vec<_>>(),
+            vec![UPoint { x: 2, y: 0 }, UPoint { x: 1, y: 1 }]
+        );
+        assert_eq!(&!&bits, &grid(&["##.", "#.#"]));
+    }
+
+    #[test]
+    fn test_shifts() {
+        let bits = grid(&["#..#", ".##.", "...#"]);
+        assert_eq!(bits.shifted_left(), grid(&["..#.", "##..", "..#."]));
+        assert_eq!(bits.shifted_right(), grid(&[".#..", "..##", "...."]));
+        assert_eq!(bits.shifted_up(), grid(&[".##.", "...#", "...."]));
+        assert_eq!(bits.shifted_down(), grid(&["....", "#..#", ".##."]));
+    }
+
+    #[test]
+    fn test_shifts_across_words() {
+        let mut bits = BitGrid::new(130, 1);
+        bits.set(&UPoint { x: 63, y: 0 }, true);
+        bits.set(&UPoint { x: 129, y: 0 }, true);
+        let right = bits.shifted_right();
+        assert_eq!(
+            right.points().collect::<Vec<_>>(),
+            vec![UPoint { x: 64, y: 0 }]
+        );
+        let left = right.shifted_left().shifted_left();
+        assert_eq!(
+            left.points().collect::<Vec<_>>(),
+            vec![UPoint { x: 62, y: 0 }]
+        );
+    }
+
+    #[test]
+    fn test_game_of_life() {
+        // a blinker flips between horizontal and vertical, found purely with whole grid ops.
+        let step = |bits: &BitGrid| {
+            let (up, down) = (bits.shifted_up(), bits.shifted_down());
+            let neighbours = [
+                bits.shifted_left(),
+                bits.shifted_right(),
+                up.shifted_left(),
+                up.shifted_right(),
+                down.shifted_left(),
+                down.shifted_right(),
+                up,
+                down,
+            ];
+            // add up the neighbours as a 3 bit counter, which is plenty to tell 2 and 3 apart.
+            let (mut ones, mut twos, mut fours) = (
+                BitGrid::new(bits.width(), bits.height()),
+                BitGrid::new(bits.width(), bits.height()),
+                BitGrid::new(bits.width(), bits.height()),
+            );
+            for n in &neighbours {
+                let carry_one = &ones & n;
+                ones = &ones ^ n;
+                let carry_two = &twos & &carry_one;
+                twos = &twos ^ &carry_one;
+                fours = &fours | &carry_two;
+            }
+            let two_or_three = &twos & &!&fours;
+            &two_or_three & &(&ones | bits)
+        };
+        let horizontal = grid(&[".....", ".....", ".###.", ".....", "....."]);
+        let vertical = grid(&[".....", "..#..", "..#..", "..#..", "....."]);
+        assert_eq!(step(&horizontal), vertical);
+        assert_eq!(step(&vertical), horizontal);
+    }
+}
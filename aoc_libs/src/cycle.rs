@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// the shape of a sequence of states that eventually repeats: the first prefix_len states are
+/// never seen again, after which the sequence loops every cycle_len steps forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix_len: usize,
+    pub cycle_len: usize,
+}
+
+impl Cycle {
+    /// maps any step number onto the earliest step with the same state, so that step n can be
+    /// looked up without simulating all n steps.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix_len {
+            n
+        } else {
+            self.prefix_len + (n - self.prefix_len) % self.cycle_len
+        }
+    }
+}
+
+/// finds the cycle using Brents algorithm. Only needs a couple of states in memory at a time, at
+/// the cost of calling step a few more times than the hash based approach.
+pub fn brent<T, F>(start: T, mut step: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    // find the cycle length by teleporting the tortoise to the hare at every power of two.
+    let mut power = 1;
+    let mut cycle_len = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == cycle_len {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_len = 0;
+        }
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+
+    // then walk two pointers cycle_len apart from the start until they meet at the loop entrance.
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..cycle_len {
+        hare = step(&hare);
+    }
+    let mut prefix_len = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+    Cycle {
+        prefix_len,
+        cycle_len,
+    }
+}
+
+/// finds the cycle using Floyds tortoise and hare algorithm.
+pub fn floyd<T, F>(start: T, mut step: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    // the hare moves twice as fast, so they are guaranteed to meet somewhere inside the loop.
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // the meeting point is as far from the loop entrance as the start is.
+    let mut prefix_len = 0;
+    let mut tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+
+    let mut cycle_len = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+    Cycle {
+        prefix_len,
+        cycle_len,
+    }
+}
+
+/// finds the cycle by remembering every state seen so far. Calls step the minimum number of
+/// times, which matters when each step is an expensive simulation.
+pub fn seen_states<T, F>(start: T, step: F) -> Cycle
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    run_until_repeat(start, step, usize::MAX).1.unwrap()
+}
+
+/// returns the state after n steps. If the states start repeating before step n, the rest of the
+/// steps are skipped over using the cycle, so n can be as large as you like.
+pub fn state_at_step<T, F>(start: T, step: F, n: usize) -> T
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let (mut history, cycle) = run_until_repeat(start, step, n);
+    match cycle {
+        Some(cycle) => history.swap_remove(cycle.equivalent_step(n)),
+        None => history.pop().unwrap(),
+    }
+}
+
+/// steps until either a state repeats or max_steps is reached, returning every state seen in
+/// order, plus the cycle if one was found.
+fn run_until_repeat<T, F>(start: T, mut step: F, max_steps: usize) -> (Vec<T>, Option<Cycle>)
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut history = vec![start];
+    loop {
+        let current = history.last().unwrap();
+        let index = history.len() - 1;
+        if let Some(first_seen) = seen.insert(current.clone(), index) {
+            history.pop();
+            let cycle = Cycle {
+                prefix_len: first_seen,
+                cycle_len: index - first_seen,
+            };
+            return (history, Some(cycle));
+        }
+        if index == max_steps {
+            return (history, None);
+        }
+        let next = step(current);
+        history.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3, so a prefix of 3 and a cycle of 4.
+    fn example_step(state: &usize) -> usize {
+        if *state == 6 {
+            3
+        } else {
+            state + 1
+        }
+    }
+
+    const EXAMPLE_CYCLE: Cycle = Cycle {
+        prefix_len: 3,
+        cycle_len: 4,
+    };
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(0, example_step), EXAMPLE_CYCLE);
+        assert_eq!(
+            brent(0_u64, |x| (x * x + 1) % 255),
+            seen_states(0_u64, |x| (x * x + 1) % 255)
+        );
+    }
+
+    #[test]
+    fn test_floyd() {
+        assert_eq!(floyd(0, example_step), EXAMPLE_CYCLE);
+        assert_eq!(
+            floyd(0_u64, |x| (x * x + 1) % 255),
+            seen_states(0_u64, |x| (x * x + 1) % 255)
+        );
+    }
+
+    #[test]
+    fn test_seen_states() {
+        assert_eq!(seen_states(0, example_step), EXAMPLE_CYCLE);
+        // a state that maps straight back to itself.
+        assert_eq!(
+            seen_states(7, |x| *x),
+            Cycle {
+                prefix_len: 0,
+                cycle_len: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_equivalent_step() {
+        assert_eq!(EXAMPLE_CYCLE.equivalent_step(2), 2);
+        assert_eq!(EXAMPLE_CYCLE.equivalent_step(3), 3);
+        assert_eq!(EXAMPLE_CYCLE.equivalent_step(7), 3);
+        assert_eq!(EXAMPLE_CYCLE.equivalent_step(9), 5);
+    }
+
+    #[test]
+    fn test_state_at_step() {
+        for n in 0..20 {
+            let mut simulated = 0;
+            for _ in 0..n {
+                simulated = example_step(&simulated);
+            }
+            assert_eq!(state_at_step(0, example_step, n), simulated);
+        }
+        // (1_000_000_000 - 3) % 4 == 1, so we land one step into the loop, on state 4.
+        assert_eq!(state_at_step(0, example_step, 1_000_000_000), 4);
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::error::AocError;
+use crate::input;
+use crate::solution::{Answer, Run, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    /// the part with the given number, 1 or 2.
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part One"),
+            Part::Two => write!(f, "Part Two"),
+        }
+    }
+}
+
+/// everything needed to run a day, so the runner can treat every day the same way. Each day crate
+/// exposes one of these as DAY.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    /// solves the given parts from the raw puzzle input, see Solution::run.
+    pub run: fn(&str, &[Part]) -> Result<Run, AocError>,
+}
+
+impl Day {
+    /// the Day for a Solution, so each day crate can make its DAY as a const.
+    pub const fn new<S: Solution>(number: u8) -> Day {
+        Day {
+            number,
+            run: S::run,
+        }
+    }
+
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Run, AocError> {
+        (self.run)(input, parts)
+    }
+
+    pub fn solve(&self, part: Part, input: &str) -> Result<Answer, AocError> {
+        let run = self.run(input, &[part])?;
+        Ok(run.parts[0].answer.clone())
+    }
+
+    /// what a days own binary does: reads the input from --input <path> (- for stdin), or from
+    /// the default path, and prints both answers. Prints the error and exits if anything fails.
+    pub fn main(&self) {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        let path = match args.as_slice() {
+            [] => None,
+            [flag, path] if flag == "--input" => Some(PathBuf::from(path)),
+            _ => {
+                eprintln!("usage: day{:02} [--input <path>]", self.number);
+                std::process::exit(2)
+            }
+        };
+        if let Err(e) = self.print_answers(path.as_deref()) {
+            eprintln!("{e}");
+            std::process::exit(1)
+        }
+    }
+
+    fn print_answers(&self, path: Option<&Path>) -> Result<(), AocError> {
+        let input = input::load(self.number, path)?;
+        for part in self.run(&input, &Part::BOTH)?.parts {
+            println!("{}", part.part);
+            println!("Result: {}", part.answer);
+        }
+        Ok(())
+    }
+}
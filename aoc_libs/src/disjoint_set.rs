@@ -0,0 +1,312 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// a union find over the dense ids 0..len, for tracking which things have been joined together.
+/// Uses union by rank and path compression, so every operation is as good as constant time.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    // only kept up to date for the roots.
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// n ids, each in a component of their own.
+    pub fn new(n: usize) -> DisjointSet {
+        DisjointSet {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    /// adds a new id in a component of its own, and returns it.
+    pub fn add(&mut self) -> usize {
+        let id = self.parent.len();
+        self.parent.push(id);
+        self.rank.push(0);
+        self.size.push(1);
+        self.components += 1;
+        id
+    }
+
+    /// the number of ids.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// the number of separate components.
+    pub fn num_components(&self) -> usize {
+        self.components
+    }
+
+    /// the representative of the component id is in. Two ids are in the same component exactly
+    /// when they have the same representative.
+    pub fn find(&mut self, id: usize) -> usize {
+        let mut root = id;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // point everything on the way straight at the root, so next time is quicker.
+        let mut current = id;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// joins the components a and b are in. Returns false if they were already joined.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // hang the shallower tree off the deeper one, so the trees stay shallow.
+        let (root, child) = if self.rank[a] < self.rank[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parent[child] = root;
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.size[root] += self.size[child];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// the number of ids in the same component as id, including itself.
+    pub fn size_of(&mut self, id: usize) -> usize {
+        let root = self.find(id);
+        self.size[root]
+    }
+
+    /// every component, as a list of its ids. Both the components and the ids in them are in
+    /// order of their smallest id.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index_of_root = HashMap::new();
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        for id in 0..self.len() {
+            let root = self.find(id);
+            let index = *index_of_root.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[index].push(id);
+        }
+        components
+    }
+}
+
+/// a DisjointSet over any hashable keys, like points, rather than dense ids. Keys are added the
+/// first time they are seen.
+#[derive(Debug, Clone)]
+pub struct HashedDisjointSet<K> {
+    keys: Vec<K>,
+    ids: HashMap<K, usize>,
+    set: DisjointSet,
+}
+
+impl<K> HashedDisjointSet<K>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn new() -> HashedDisjointSet<K> {
+        HashedDisjointSet {
+            keys: Vec::new(),
+            ids: HashMap::new(),
+            set: DisjointSet::new(0),
+        }
+    }
+
+    /// adds key in a component of its own, if it isnt there already.
+    pub fn insert<Q>(&mut self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(id) = self.ids.get(key) {
+            return *id;
+        }
+        let id = self.set.add();
+        self.keys.push(key.to_owned());
+        self.ids.insert(key.to_owned(), id);
+        id
+    }
+
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.ids.contains_key(key)
+    }
+
+    /// the number of keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// the number of separate components.
+    pub fn num_components(&self) -> usize {
+        self.set.num_components()
+    }
+
+    /// joins the components a and b are in, adding either of them if they are new. Returns false
+    /// if they were already joined.
+    pub fn union<Q>(&mut self, a: &Q, b: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.set.union(a, b)
+    }
+
+    /// whether a and b are in the same component. Keys that were never added arent connected to
+    /// anything.
+    pub fn connected<Q>(&mut self, a: &Q, b: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match (self.ids.get(a), self.ids.get(b)) {
+            (Some(a), Some(b)) => self.set.connected(*a, *b),
+            _ => false,
+        }
+    }
+
+    /// the number of keys in the same component as key, or none if it was never added.
+    pub fn size_of<Q>(&mut self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let id = *self.ids.get(key)?;
+        Some(self.set.size_of(id))
+    }
+
+    /// every component, as a list of its keys. Both are in the order the keys were first added.
+    pub fn components(&mut self) -> Vec<Vec<K>> {
+        self.set
+            .components()
+            .into_iter()
+            .map(|c| c.into_iter().map(|id| self.keys[id].clone()).collect())
+            .collect()
+    }
+}
+
+impl<K> Default for HashedDisjointSet<K>
+where
+    K: Hash + Eq + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::points::Point;
+
+    #[test]
+    fn test_union_find() {
+        let mut set = DisjointSet::new(6);
+        assert_eq!(set.num_components(), 6);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+        assert!(set.connected(0, 3));
+        assert!(!set.connected(0, 4));
+        assert_eq!(set.size_of(2), 4);
+        assert_eq!(set.size_of(5), 1);
+        assert_eq!(set.num_components(), 3);
+        assert_eq!(set.components(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn test_add() {
+        let mut set = DisjointSet::default();
+        assert!(set.is_empty());
+        let a = set.add();
+        let b = set.add();
+        assert_eq!((a, b), (0, 1));
+        set.union(a, b);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.num_components(), 1);
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // lots of unions in a row shouldnt build a deep tree, or blow the stack finding the root.
+        let n = 100_000;
+        let mut set = DisjointSet::new(n);
+        for i in 1..n {
+            set.union(i - 1, i);
+        }
+        assert_eq!(set.num_components(), 1);
+        assert_eq!(set.size_of(0), n);
+        assert!(set.connected(0, n - 1));
+    }
+
+    #[test]
+    fn test_hashed() {
+        let mut set: HashedDisjointSet<Point> = HashedDisjointSet::new();
+        let points = [
+            Point { x: 0, y: 0 },
+            Point { x: 1, y: 0 },
+            Point { x: 5, y: 5 },
+            Point { x: 5, y: 6 },
+            Point { x: -3, y: 2 },
+        ];
+        set.insert(&points[4]);
+        set.union(&points[0], &points[1]);
+        set.union(&points[2], &points[3]);
+        assert_eq!(set.len(), 5);
+        assert_eq!(set.num_components(), 3);
+        assert!(set.connected(&points[2], &points[3]));
+        assert!(!set.connected(&points[1], &points[2]));
+        assert!(!set.connected(&points[1], &Point { x: 9, y: 9 }));
+        assert_eq!(set.size_of(&points[0]), Some(2));
+        assert_eq!(set.size_of(&Point { x: 9, y: 9 }), None);
+        assert_eq!(
+            set.components(),
+            vec![
+                vec![points[4]],
+                vec![points[0], points[1]],
+                vec![points[2], points[3]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hashed_borrowed_keys() {
+        let mut set: HashedDisjointSet<String> = HashedDisjointSet::default();
+        set.union("a", "b");
+        set.union("c", "b");
+        assert!(set.contains("c"));
+        assert!(set.connected("a", "c"));
+        assert_eq!(set.size_of("a"), Some(3));
+    }
+}
@@ -0,0 +1,173 @@
+use nom::error::ErrorKind;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AocError {
+    /// the input didnt match what the parser expected. line and column are 1 based, and snippet
+    /// is the offending line with the problem underlined.
+    #[error("parse error on line {line}, column {column}: expected {expected}\n{snippet}")]
+    Parse {
+        line: usize,
+        column: usize,
+        expected: String,
+        snippet: String,
+    },
+    /// the puzzle input couldnt be read. reason is the underlying io error, as text so the error
+    /// can still be compared and cloned.
+    #[error("couldnt read input from {path}: {reason}")]
+    Input { path: String, reason: String },
+}
+
+impl AocError {
+    /// makes a parse error for the problem starting offset bytes into input.
+    pub fn parse(input: &str, offset: usize, expected: impl Into<String>) -> AocError {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find(['\r', '\n'])
+            .map_or(input.len(), |i| offset + i);
+        let line = input[..offset].matches('\n').count() + 1;
+        let column = input[line_start..offset].chars().count() + 1;
+        // underline the whole token that went wrong, not just its first char.
+        let token_len = input[offset..line_end]
+            .split(char::is_whitespace)
+            .next()
+            .map_or(0, |token| token.chars().count())
+            .max(1);
+
+        let gutter = " ".repeat(line.to_string().len());
+        let snippet = format!(
+            "{gutter} |\n{line} | {}\n{gutter} | {}{}",
+            &input[line_start..line_end],
+            " ".repeat(column - 1),
+            "^".repeat(token_len),
+        );
+        AocError::Parse {
+            line,
+            column,
+            expected: expected.into(),
+            snippet,
+        }
+    }
+
+    /// makes a parse error pointing at the start of at, which has to be a slice of input, like
+    /// the remaining input a nom parser gives back.
+    pub fn parse_at(input: &str, at: &str, expected: impl Into<String>) -> AocError {
+        Self::parse(input, offset_of(input, at), expected)
+    }
+
+    /// makes an input error for a failed read of path. A missing file says how to supply one,
+    /// since thats nearly always what went wrong.
+    pub fn input(path: impl Into<String>, err: &std::io::Error) -> AocError {
+        let reason = match err.kind() {
+            std::io::ErrorKind::NotFound => {
+                "no such file, save your puzzle input there or pass --input <path>".to_string()
+            }
+            _ => err.to_string(),
+        };
+        AocError::Input {
+            path: path.into(),
+            reason,
+        }
+    }
+
+    /// turns a failed nom parse of (some slice of) input into a parse error.
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> AocError {
+        match err {
+            nom::Err::Incomplete(_) => Self::parse(input, input.len(), "more input"),
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                Self::parse_at(input, e.input, describe(e.code))
+            }
+        }
+    }
+}
+
+/// where at starts in input, in bytes. If at isnt actually a slice of input, assumes its the end
+/// of it.
+fn offset_of(input: &str, at: &str) -> usize {
+    (at.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|offset| *offset <= input.len())
+        .unwrap_or(input.len().saturating_sub(at.len()))
+}
+
+/// what a nom parser that failed with kind was looking for, in words.
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "a number",
+        ErrorKind::MapRes => "a valid number",
+        ErrorKind::MapOpt | ErrorKind::Verify => "a valid value",
+        ErrorKind::Tag => "a keyword or separator",
+        ErrorKind::Char | ErrorKind::OneOf => "a separator",
+        ErrorKind::NoneOf => "a different character",
+        ErrorKind::Alpha => "a letter",
+        ErrorKind::AlphaNumeric => "a letter or digit",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::CrLf => "a line ending",
+        ErrorKind::Eof => "end of input",
+        ErrorKind::Not => "end of line",
+        ErrorKind::Many1 | ErrorKind::SeparatedList | ErrorKind::Count => "at least one item",
+        other => return other.description().to_lowercase(),
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Game 1: 3 blue\nGame 2: x blue\n";
+        let err = AocError::parse(input, 23, "a number");
+        let AocError::Parse { line, column, .. } = &err else {
+            panic!("expected a parse error")
+        };
+        assert_eq!((*line, *column), (2, 9));
+        assert_eq!(
+            err.to_string(),
+            concat!(
+                "parse error on line 2, column 9: expected a number\n",
+                "  |\n",
+                "2 | Game 2: x blue\n",
+                "  |         ^"
+            )
+        );
+    }
+
+    #[test]
+    fn test_underlines_token() {
+        let err = AocError::parse("32T3K 765\r\n", 6, "a bid");
+        let AocError::Parse { snippet, .. } = err else {
+            panic!("expected a parse error")
+        };
+        assert_eq!(snippet, "  |\n1 | 32T3K 765\n  |       ^^^");
+    }
+
+    #[test]
+    fn test_from_nom() {
+        let input = "seeds: 1 2\nfoo";
+        let line = &input[11..];
+        let err =
+            nom::character::complete::digit1::<&str, nom::error::Error<&str>>(line).unwrap_err();
+        let AocError::Parse {
+            line,
+            column,
+            expected,
+            ..
+        } = AocError::from_nom(input, err)
+        else {
+            panic!("expected a parse error")
+        };
+        assert_eq!((line, column), (2, 1));
+        assert_eq!(expected, "a number");
+    }
+
+    #[test]
+    fn test_end_of_input() {
+        let AocError::Parse { line, column, .. } = AocError::parse("ab\ncd", 5, "more") else {
+            panic!("expected a parse error")
+        };
+        assert_eq!((line, column), (2, 3));
+    }
+}
@@ -0,0 +1,160 @@
+use std::path::{Path, PathBuf};
+
+use crate::day::Part;
+use crate::error::AocError;
+
+/// an example input from the puzzle, with the answers it should give. On disk its kept in
+/// examples/dayNN/<name>.txt, with the answers it has first, then a line of ---, then the input:
+///
+/// ```text
+/// part1: 142
+/// part2: 142
+/// ---
+/// 1abc2
+/// pqr3stu8vwx
+/// ```
+///
+/// some examples only have an answer for one part, like when part 2 comes with its own example.
+/// An answer left blank, like in a new days example, is the same as not having one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    /// reads an example from the text of its file. The error is why it couldnt be.
+    pub fn parse(text: &str) -> Result<Example, String> {
+        let mut example = Example {
+            input: String::new(),
+            part1: None,
+            part2: None,
+        };
+        let mut offset = 0;
+        for line in text.split_inclusive('\n') {
+            offset += line.len();
+            let line = line.trim();
+            if line == "---" {
+                example.input = text[offset..].to_string();
+                return Ok(example);
+            }
+            if line.is_empty() {
+                continue;
+            }
+            let (part, answer) = match line.split_once(':') {
+                Some(("part1", answer)) => (&mut example.part1, answer),
+                Some(("part2", answer)) => (&mut example.part2, answer),
+                _ => return Err(format!("expected an answer like part1: 142, found {line}")),
+            };
+            let answer = answer.trim();
+            *part = (!answer.is_empty()).then(|| answer.to_string());
+        }
+        Err("expected a line of --- between the answers and the input".to_string())
+    }
+
+    /// the answer part should give, if the example has one.
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    /// the parts the example has answers for.
+    pub fn parts(&self) -> Vec<Part> {
+        Part::BOTH
+            .into_iter()
+            .filter(|part| self.answer(*part).is_some())
+            .collect()
+    }
+}
+
+/// where the example called name for day is kept, relative to where it is run from.
+pub fn path(day: u8, name: &str) -> PathBuf {
+    PathBuf::from(format!("examples/day{day:02}/{name}.txt"))
+}
+
+pub fn read(path: &Path) -> Result<Example, AocError> {
+    let error = |reason: String| AocError::Input {
+        path: path.display().to_string(),
+        reason,
+    };
+    let text = std::fs::read_to_string(path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => error("no such example".to_string()),
+        _ => error(e.to_string()),
+    })?;
+    Example::parse(&text).map_err(error)
+}
+
+/// reads an example from the workspace, wherever its run from. For tests and benches, which run
+/// from their own crate.
+pub fn load(day: u8, name: &str) -> Result<Example, AocError> {
+    read(
+        &Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(path(day, name)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let example = Example::parse("part1: 142\npart2: 281\n---\n1abc2\ntreb7uchet\n").unwrap();
+        assert_eq!(example.input, "1abc2\ntreb7uchet\n");
+        assert_eq!(example.answer(Part::One), Some("142"));
+        assert_eq!(example.answer(Part::Two), Some("281"));
+        assert_eq!(example.parts(), Part::BOTH.to_vec());
+    }
+
+    #[test]
+    fn test_parse_one_part() {
+        let example = Example::parse("part2: 281\r\n\r\n---\r\ntwo1nine\r\n").unwrap();
+        assert_eq!(example.input, "two1nine\r\n");
+        assert_eq!(example.answer(Part::One), None);
+        assert_eq!(example.parts(), vec![Part::Two]);
+    }
+
+    #[test]
+    fn test_parse_blank() {
+        let example = Example::parse("part1:\npart2:\n---\n").unwrap();
+        assert_eq!(example.input, "");
+        assert!(example.parts().is_empty());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Example::parse("part3: 1\n---\n"),
+            Err("expected an answer like part1: 142, found part3: 1".to_string())
+        );
+        assert_eq!(
+            Example::parse("part1: 1\n1abc2\n"),
+            Err("expected an answer like part1: 142, found 1abc2".to_string())
+        );
+        assert_eq!(
+            Example::parse("part1: 1\n"),
+            Err("expected a line of --- between the answers and the input".to_string())
+        );
+    }
+
+    #[test]
+    fn test_path() {
+        assert_eq!(
+            path(7, "example"),
+            PathBuf::from("examples/day07/example.txt")
+        );
+    }
+
+    #[test]
+    fn test_missing_example() {
+        let err = read(Path::new("no/such/example.txt")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "couldnt read input from no/such/example.txt: no such example"
+        );
+    }
+}
@@ -0,0 +1,456 @@
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::graph::{Graph, NodeId};
+
+/// a partition of a graphs nodes into two groups, along with the edges that cross between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    /// the total weight of the edges crossing the cut.
+    pub weight: u64,
+    /// one side of the cut. For a source-sink cut, this is the side containing the source.
+    pub first: Vec<NodeId>,
+    /// the other side of the cut.
+    pub second: Vec<NodeId>,
+    /// the edges crossing the cut, as (from, to) pairs. For a source-sink cut this is only the
+    /// edges leading from the first side to the second, as the others dont limit the flow.
+    pub edges: Vec<(NodeId, NodeId)>,
+}
+
+impl Cut {
+    /// builds the cut for a given partition, where in_first says which side each node is on.
+    /// If directed is set, only edges from the first side to the second are included.
+    fn from_partition<N, L>(
+        graph: &Graph<N, L>,
+        in_first: &[bool],
+        weight: u64,
+        directed: bool,
+    ) -> Cut
+    where
+        N: Hash + Eq + Clone,
+    {
+        let (first, second) = graph.node_ids().partition(|n| in_first[*n as usize]);
+        let edges = graph
+            .node_ids()
+            .flat_map(|from| graph.neighbours(from).map(move |to| (from, to)))
+            .filter(|(from, to)| {
+                let (from, to) = (in_first[*from as usize], in_first[*to as usize]);
+                if directed {
+                    from && !to
+                } else {
+                    from != to
+                }
+            })
+            .collect();
+        Cut {
+            weight,
+            first,
+            second,
+            edges,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxFlow {
+    /// how much can flow from the source to the sink.
+    pub value: u64,
+    /// the minimum source-sink cut, whose weight always equals the flow.
+    pub min_cut: Cut,
+}
+
+/// finds the cheapest way to split the graph into two non-empty groups, using the Stoer-Wagner
+/// algorithm. Edges are treated as undirected, so each connection should only be added once
+/// (with add_edge rather than add_undirected_edge), otherwise it gets counted twice.
+/// weight gives the weight of each edge from its label, eg |_| 1 for an unweighted graph.
+/// Returns none if the graph has fewer than two nodes, and so cant be split.
+pub fn min_cut<N, L, F>(graph: &Graph<N, L>, weight: F) -> Option<Cut>
+where
+    N: Hash + Eq + Clone,
+    F: Fn(&L) -> u64,
+{
+    if graph.len() < 2 {
+        return None;
+    }
+    let mut adjacency: Vec<HashMap<usize, u64>> = vec![HashMap::new(); graph.len()];
+    for from in graph.node_ids() {
+        for edge in graph.edges(from) {
+            let (from, to) = (from as usize, edge.to as usize);
+            if from != to {
+                *adjacency[from].entry(to).or_insert(0) += weight(&edge.label);
+                *adjacency[to].entry(from).or_insert(0) += weight(&edge.label);
+            }
+        }
+    }
+    // the original nodes each merged node stands for.
+    let mut members: Vec<Vec<usize>> = (0..graph.len()).map(|n| vec![n]).collect();
+    let mut active: Vec<usize> = (0..graph.len()).collect();
+    let mut best: Option<(u64, Vec<usize>)> = None;
+
+    while active.len() > 1 {
+        // maximum adjacency ordering: keep adding whichever node is most tightly connected to
+        // the nodes added so far. The cut between the last node and everything else is the
+        // cheapest cut that separates the last two nodes.
+        let mut connection = vec![0_u64; graph.len()];
+        let mut added = vec![false; graph.len()];
+        let mut heap: BinaryHeap<(u64, usize)> = active.iter().map(|n| (0, *n)).collect();
+        let mut previous = None;
+        let mut last = None;
+        while let Some((strength, node)) = heap.pop() {
+            if added[node] || strength != connection[node] {
+                continue;
+            }
+            added[node] = true;
+            previous = last;
+            last = Some(node);
+            for (next, weight) in &adjacency[node] {
+                if !added[*next] {
+                    connection[*next] += weight;
+                    heap.push((connection[*next], *next));
+                }
+            }
+        }
+        let (previous, last) = (previous.unwrap(), last.unwrap());
+        if best.as_ref().is_none_or(|b| connection[last] < b.0) {
+            best = Some((connection[last], members[last].clone()));
+        }
+
+        // merge the last node into the one before it.
+        let last_edges = std::mem::take(&mut adjacency[last]);
+        for (next, weight) in last_edges {
+            adjacency[next].remove(&last);
+            if next != previous {
+                *adjacency[previous].entry(next).or_insert(0) += weight;
+                *adjacency[next].entry(previous).or_insert(0) += weight;
+            }
+        }
+        let last_members = std::mem::take(&mut members[last]);
+        members[previous].extend(last_members);
+        active.retain(|n| *n != last);
+    }
+
+    let (weight, side) = best.unwrap();
+    let mut in_first = vec![false; graph.len()];
+    for node in side {
+        in_first[node] = true;
+    }
+    Some(Cut::from_partition(graph, &in_first, weight, false))
+}
+
+/// a residual edge for dinics algorithm.
+struct ResidualEdge {
+    to: usize,
+    capacity: u64,
+    /// index of the paired edge going the other way in the edges list of to.
+    reverse: usize,
+}
+
+/// finds the maximum flow from source to sink using Dinics algorithm, along with the minimum
+/// cut that limits it. Edges are directed: to allow flow both ways along a connection, add it in
+/// both directions (eg with add_undirected_edge).
+/// capacity gives the capacity of each edge from its label, eg |_| 1 for an unweighted graph.
+pub fn max_flow<N, L, F>(graph: &Graph<N, L>, source: NodeId, sink: NodeId, capacity: F) -> MaxFlow
+where
+    N: Hash + Eq + Clone,
+    F: Fn(&L) -> u64,
+{
+    assert_ne!(source, sink, "source and sink must be different nodes");
+    let mut residual: Vec<Vec<ResidualEdge>> = (0..graph.len()).map(|_| Vec::new()).collect();
+    for from in graph.node_ids() {
+        for edge in graph.edges(from) {
+            let (from, to) = (from as usize, edge.to as usize);
+            let forward = residual[from].len();
+            let backward = residual[to].len() + usize::from(from == to);
+            residual[from].push(ResidualEdge {
+                to,
+                capacity: capacity(&edge.label),
+                reverse: backward,
+            });
+            residual[to].push(ResidualEdge {
+                to: from,
+                capacity: 0,
+                reverse: forward,
+            });
+        }
+    }
+
+    let (source, sink) = (source as usize, sink as usize);
+    let mut value = 0;
+    loop {
+        let level = bfs_levels(&residual, source);
+        if level[sink].is_none() {
+            break;
+        }
+        // the next edge to try from each node, so dead ends are never retried in this phase.
+        let mut next_edge = vec![0; residual.len()];
+        loop {
+            let pushed = push_flow(&mut residual, &level, &mut next_edge, source, sink);
+            if pushed == 0 {
+                break;
+            }
+            value += pushed;
+        }
+    }
+
+    // whatever the source can still reach in the residual graph is its side of the min cut.
+    let in_first: Vec<bool> = bfs_levels(&residual, source)
+        .iter()
+        .map(|l| l.is_some())
+        .collect();
+    MaxFlow {
+        value,
+        min_cut: Cut::from_partition(graph, &in_first, value, true),
+    }
+}
+
+/// distance from the source to every node using only edges with capacity left.
+fn bfs_levels(residual: &[Vec<ResidualEdge>], source: usize) -> Vec<Option<usize>> {
+    let mut level = vec![None; residual.len()];
+    level[source] = Some(0);
+    let mut queue = VecDeque::from([source]);
+    while let Some(node) = queue.pop_front() {
+        for edge in &residual[node] {
+            if edge.capacity > 0 && level[edge.to].is_none() {
+                level[edge.to] = Some(level[node].unwrap() + 1);
+                queue.push_back(edge.to);
+            }
+        }
+    }
+    level
+}
+
+/// pushes flow along one path from the source to the sink whose edges go one level deeper each
+/// step, returning how much was pushed. The path is kept on an explicit stack rather than
+/// recursing, so long paths cant blow the real stack.
+fn push_flow(
+    residual: &mut [Vec<ResidualEdge>],
+    level: &[Option<usize>],
+    next_edge: &mut [usize],
+    source: usize,
+    sink: usize,
+) -> u64 {
+    // the nodes from the source to where the search has got to. Each one but the last goes on
+    // along its next_edge.
+    let mut path = vec![source];
+    while let Some(&node) = path.last() {
+        if node == sink {
+            let along = &path[..path.len() - 1];
+            let pushed = along
+                .iter()
+                .map(|&from| residual[from][next_edge[from]].capacity)
+                .min()
+                .unwrap();
+            for &from in along {
+                let edge = &mut residual[from][next_edge[from]];
+                edge.capacity -= pushed;
+                let (to, reverse) = (edge.to, edge.reverse);
+                residual[to][reverse].capacity += pushed;
+            }
+            return pushed;
+        }
+        match residual[node].get(next_edge[node]) {
+            Some(edge) if edge.capacity > 0 && level[edge.to] == level[node].map(|l| l + 1) => {
+                path.push(edge.to);
+            }
+            Some(_) => next_edge[node] += 1,
+            None => {
+                // a dead end, so back up and dont come this way again.
+                path.pop();
+                if let Some(&from) = path.last() {
+                    next_edge[from] += 1;
+                }
+            }
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wiring_diagram() -> Graph<String> {
+        let input = concat!(
+            "jqt: rhn xhk nvd\n",
+            "rsh: frs pzl lsr\n",
+            "xhk: hfx\n",
+            "cmg: qnr nvd lhk bvb\n",
+            "rhn: xhk bvb hfx\n",
+            "bvb: xhk hfx\n",
+            "pzl: lsr hfx nvd\n",
+            "qnr: nvd\n",
+            "ntq: jqt hfx bvb xhk\n",
+            "nvd: lhk\n",
+            "lsr: lhk\n",
+            "rzs: qnr cmg lsr rsh\n",
+            "frs: qnr lhk lsr\n",
+        );
+        let mut graph = Graph::new();
+        for line in input.lines() {
+            let (from, tos) = line.split_once(": ").unwrap();
+            for to in tos.split(' ') {
+                graph.add_named_edge(from, to, ());
+            }
+        }
+        graph
+    }
+
+    fn edge_names(graph: &Graph<String, impl Sized>, edges: &[(NodeId, NodeId)]) -> Vec<String> {
+        let mut names: Vec<String> = edges
+            .iter()
+            .map(|(a, b)| {
+                let (a, b) = (graph.name(*a), graph.name(*b));
+                if a < b {
+                    format!("{}/{}", a, b)
+                } else {
+                    format!("{}/{}", b, a)
+                }
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_min_cut() {
+        let graph = wiring_diagram();
+        let cut = min_cut(&graph, |_| 1).unwrap();
+        assert_eq!(cut.weight, 3);
+        assert_eq!(cut.first.len() * cut.second.len(), 54);
+        assert_eq!(
+            edge_names(&graph, &cut.edges),
+            vec!["bvb/cmg", "hfx/pzl", "jqt/nvd"]
+        );
+    }
+
+    #[test]
+    fn test_min_cut_weighted() {
+        // the example from the Stoer-Wagner paper.
+        let mut graph: Graph<u32, u64> = Graph::new();
+        for (from, to, weight) in [
+            (1, 2, 2),
+            (1, 5, 3),
+            (2, 3, 3),
+            (2, 5, 2),
+            (2, 6, 2),
+            (3, 4, 4),
+            (3, 7, 2),
+            (4, 7, 2),
+            (4, 8, 2),
+            (5, 6, 3),
+            (6, 7, 1),
+            (7, 8, 3),
+        ] {
+            graph.add_named_edge(&from, &to, weight);
+        }
+        let cut = min_cut(&graph, |w| *w).unwrap();
+        assert_eq!(cut.weight, 4);
+        let mut side: Vec<u32> = cut.first.iter().map(|n| *graph.name(*n)).collect();
+        side.sort();
+        if side[0] == 1 {
+            assert_eq!(side, vec![1, 2, 5, 6]);
+        } else {
+            assert_eq!(side, vec![3, 4, 7, 8]);
+        }
+    }
+
+    #[test]
+    fn test_min_cut_too_small() {
+        let mut graph: Graph<u32> = Graph::new();
+        graph.intern(&1);
+        assert_eq!(min_cut(&graph, |_| 1), None);
+    }
+
+    #[test]
+    fn test_max_flow() {
+        let mut graph: Graph<&str, u64> = Graph::new();
+        for (from, to, capacity) in [
+            ("s", "v1", 16),
+            ("s", "v2", 13),
+            ("v1", "v3", 12),
+            ("v2", "v1", 4),
+            ("v2", "v4", 14),
+            ("v3", "v2", 9),
+            ("v3", "t", 20),
+            ("v4", "v3", 7),
+            ("v4", "t", 4),
+        ] {
+            graph.add_named_edge(&from, &to, capacity);
+        }
+        let source = graph.id(&"s").unwrap();
+        let sink = graph.id(&"t").unwrap();
+        let flow = max_flow(&graph, source, sink, |c| *c);
+        assert_eq!(flow.value, 23);
+        assert_eq!(flow.min_cut.weight, 23);
+        let mut sink_side: Vec<&str> = flow
+            .min_cut
+            .second
+            .iter()
+            .map(|n| *graph.name(*n))
+            .collect();
+        sink_side.sort();
+        assert_eq!(sink_side, vec!["t", "v3"]);
+        let cut_capacity: u64 = flow
+            .min_cut
+            .edges
+            .iter()
+            .map(|(from, to)| {
+                graph
+                    .edges(*from)
+                    .iter()
+                    .find(|e| e.to == *to)
+                    .unwrap()
+                    .label
+            })
+            .sum();
+        assert_eq!(cut_capacity, 23);
+    }
+
+    #[test]
+    fn test_max_flow_undirected() {
+        let undirected = wiring_diagram();
+        let mut graph: Graph<String> = Graph::new();
+        for from in undirected.node_ids() {
+            for to in undirected.neighbours(from) {
+                let from = graph.intern(undirected.name(from));
+                let to = graph.intern(undirected.name(to));
+                graph.add_undirected_edge(from, to, ());
+            }
+        }
+        let flow = max_flow(
+            &graph,
+            graph.id("cmg").unwrap(),
+            graph.id("jqt").unwrap(),
+            |_| 1,
+        );
+        assert_eq!(flow.value, 3);
+        assert_eq!(flow.min_cut.first.len() * flow.min_cut.second.len(), 54);
+        // within the same group, there are always more than 3 paths.
+        let flow = max_flow(
+            &graph,
+            graph.id("cmg").unwrap(),
+            graph.id("lsr").unwrap(),
+            |_| 1,
+        );
+        assert!(flow.value > 3);
+    }
+
+    #[test]
+    fn test_max_flow_long_path() {
+        // deep enough to overflow the stack if this were recursive.
+        let mut graph: Graph<usize, u64> = Graph::new();
+        for i in 0..200_000 {
+            graph.add_named_edge(&i, &(i + 1), 3 + i as u64 % 5);
+            // a dead end off every node, which the search has to back out of.
+            graph.add_named_edge(&i, &(i + 300_000), 1);
+        }
+        graph.add_named_edge(&0, &200_000, 2);
+        let flow = max_flow(
+            &graph,
+            graph.id(&0).unwrap(),
+            graph.id(&200_000).unwrap(),
+            |c| *c,
+        );
+        assert_eq!(flow.value, 5);
+    }
+}
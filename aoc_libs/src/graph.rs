@@ -0,0 +1,534 @@
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+
+/// a dense index for a node in a Graph. Ids are handed out in the order nodes are added, starting
+/// from 0, so they can be used to index straight into a Vec.
+pub type NodeId = u32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge<L> {
+    pub to: NodeId,
+    pub label: L,
+}
+
+/// a directed graph. Node names (anything hashable, usually a String) are interned into dense
+/// NodeIds once up front, so that walking the graph afterwards never has to hash or allocate.
+/// Edges can carry a label, such as a direction to take or a weight. Use () if they dont need one.
+#[derive(Debug, Clone)]
+pub struct Graph<N, L = ()> {
+    names: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    edges: Vec<Vec<Edge<L>>>,
+}
+
+impl<N, L> Graph<N, L>
+where
+    N: Hash + Eq + Clone,
+{
+    pub fn new() -> Graph<N, L> {
+        Graph {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// returns the id of the node with the given name, adding the node if it doesnt exist yet.
+    pub fn intern<Q>(&mut self, name: &Q) -> NodeId
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = N> + ?Sized,
+    {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = NodeId::try_from(self.names.len()).expect("too many nodes for a NodeId");
+        self.names.push(name.to_owned());
+        self.ids.insert(name.to_owned(), id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    /// looks up the id of a node by name, returning none if there is no such node.
+    pub fn id<Q>(&self, name: &Q) -> Option<NodeId>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.ids.get(name).copied()
+    }
+
+    /// the name of a node. Panics if the id did not come from this graph.
+    pub fn name(&self, id: NodeId) -> &N {
+        &self.names[id as usize]
+    }
+
+    /// adds a directed edge from one node to another.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, label: L) {
+        self.edges[from as usize].push(Edge { to, label });
+    }
+
+    /// adds a directed edge between two nodes by name, interning them if needed.
+    pub fn add_named_edge<Q>(&mut self, from: &Q, to: &Q, label: L)
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = N> + ?Sized,
+    {
+        let from = self.intern(from);
+        let to = self.intern(to);
+        self.add_edge(from, to, label);
+    }
+
+    /// adds an edge in both directions.
+    pub fn add_undirected_edge(&mut self, first: NodeId, second: NodeId, label: L)
+    where
+        L: Clone,
+    {
+        self.add_edge(first, second, label.clone());
+        self.add_edge(second, first, label);
+    }
+
+    /// all edges leading out of a node, in the order they were added.
+    pub fn edges(&self, id: NodeId) -> &[Edge<L>] {
+        &self.edges[id as usize]
+    }
+
+    /// the nodes directly reachable from a node.
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges(id).iter().map(|e| e.to)
+    }
+
+    /// follows the first edge out of a node with the given label, if there is one.
+    pub fn follow(&self, id: NodeId, label: &L) -> Option<NodeId>
+    where
+        L: PartialEq,
+    {
+        self.edges(id)
+            .iter()
+            .find(|e| e.label == *label)
+            .map(|e| e.to)
+    }
+
+    /// every node id in the graph, in ascending order.
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> {
+        0..self.names.len() as NodeId
+    }
+
+    /// the number of nodes in the graph.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// every node that can be reached from start by following edges, including start itself.
+    pub fn reachable_from(&self, start: NodeId) -> HashSet<NodeId> {
+        let mut seen = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            for next in self.neighbours(node) {
+                if seen.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        seen
+    }
+
+    /// orders the nodes so that every edge points from an earlier node to a later one.
+    /// If that is impossible because the graph has a cycle, returns one such cycle as the error,
+    /// in the order its edges are followed.
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, Vec<NodeId>> {
+        // kahns algorithm: repeatedly take nodes that have nothing left pointing at them.
+        let mut in_degree = vec![0_usize; self.len()];
+        for node in self.node_ids() {
+            for next in self.neighbours(node) {
+                in_degree[next as usize] += 1;
+            }
+        }
+        let mut ready: VecDeque<NodeId> = self
+            .node_ids()
+            .filter(|n| in_degree[*n as usize] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = ready.pop_front() {
+            order.push(node);
+            for next in self.neighbours(node) {
+                in_degree[next as usize] -= 1;
+                if in_degree[next as usize] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+        if order.len() == self.len() {
+            return Ok(order);
+        }
+
+        // every leftover node still has a leftover predecessor, so walking backwards through
+        // them must eventually loop.
+        let mut predecessor = vec![None; self.len()];
+        for node in self.node_ids() {
+            for next in self.neighbours(node) {
+                if in_degree[node as usize] > 0 && in_degree[next as usize] > 0 {
+                    predecessor[next as usize] = Some(node);
+                }
+            }
+        }
+        let mut position: HashMap<NodeId, usize> = HashMap::new();
+        let mut walk = Vec::new();
+        let mut node = self
+            .node_ids()
+            .find(|n| in_degree[*n as usize] > 0)
+            .unwrap();
+        while !position.contains_key(&node) {
+            position.insert(node, walk.len());
+            walk.push(node);
+            node = predecessor[node as usize].unwrap();
+        }
+        let mut cycle = walk.split_off(position[&node]);
+        cycle.reverse();
+        Err(cycle)
+    }
+
+    /// splits the graph into strongly connected components using Tarjans algorithm. Each node
+    /// appears in exactly one component, and the components come out in reverse topological
+    /// order, ie no component has an edge into a component that comes after it.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        const UNVISITED: usize = usize::MAX;
+        let mut index = vec![UNVISITED; self.len()];
+        let mut lowlink = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in self.node_ids() {
+            if index[root as usize] != UNVISITED {
+                continue;
+            }
+            // an explicit call stack of (node, next edge to try), so that long chains cant blow
+            // the real stack.
+            let mut call_stack = vec![(root, 0)];
+            index[root as usize] = next_index;
+            lowlink[root as usize] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root as usize] = true;
+
+            while let Some((node, edge_index)) = call_stack.last_mut() {
+                let node = *node;
+                if let Some(edge) = self.edges(node).get(*edge_index) {
+                    *edge_index += 1;
+                    let next = edge.to as usize;
+                    if index[next] == UNVISITED {
+                        index[next] = next_index;
+                        lowlink[next] = next_index;
+                        next_index += 1;
+                        stack.push(edge.to);
+                        on_stack[next] = true;
+                        call_stack.push((edge.to, 0));
+                    } else if on_stack[next] {
+                        lowlink[node as usize] = lowlink[node as usize].min(index[next]);
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some((parent, _)) = call_stack.last() {
+                    lowlink[*parent as usize] =
+                        lowlink[*parent as usize].min(lowlink[node as usize]);
+                }
+                if lowlink[node as usize] == index[node as usize] {
+                    let mut component = Vec::new();
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member as usize] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// renders the graph in graphviz DOT format, using the given functions to label nodes and
+    /// edges. Edges whose label function returns none are left unlabelled.
+    pub fn to_dot_with<F, G>(&self, node_label: F, edge_label: G) -> String
+    where
+        F: Fn(&N) -> String,
+        G: Fn(&L) -> Option<String>,
+    {
+        let mut dot = String::from("digraph {\n");
+        for node in self.node_ids() {
+            let label = escape_dot(&node_label(self.name(node)));
+            writeln!(dot, "    {} [label=\"{}\"];", node, label).unwrap();
+        }
+        for node in self.node_ids() {
+            for edge in self.edges(node) {
+                match edge_label(&edge.label) {
+                    Some(label) => writeln!(
+                        dot,
+                        "    {} -> {} [label=\"{}\"];",
+                        node,
+                        edge.to,
+                        escape_dot(&label)
+                    ),
+                    None => writeln!(dot, "    {} -> {};", node, edge.to),
+                }
+                .unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// renders the graph in graphviz DOT format, labelling nodes and edges with their Display
+    /// output.
+    pub fn to_dot(&self) -> String
+    where
+        N: Display,
+        L: Display,
+    {
+        self.to_dot_with(|n| n.to_string(), |l| Some(l.to_string()))
+    }
+}
+
+fn escape_dot(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// ids is entirely derived from names, so it doesnt need comparing.
+impl<N: PartialEq, L: PartialEq> PartialEq for Graph<N, L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.names == other.names && self.edges == other.edges
+    }
+}
+
+impl<N: Eq, L: Eq> Eq for Graph<N, L> {}
+
+impl<N, L> Default for Graph<N, L>
+where
+    N: Hash + Eq + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Turn {
+        Left,
+        Right,
+    }
+
+    fn example() -> Graph<String, Turn> {
+        let mut graph = Graph::new();
+        graph.add_named_edge("AAA", "BBB", Turn::Left);
+        graph.add_named_edge("AAA", "CCC", Turn::Right);
+        graph.add_named_edge("BBB", "DDD", Turn::Left);
+        graph.add_named_edge("BBB", "EEE", Turn::Right);
+        graph.add_named_edge("CCC", "ZZZ", Turn::Left);
+        graph.add_named_edge("CCC", "GGG", Turn::Right);
+        graph
+    }
+
+    #[test]
+    fn test_intern() {
+        let mut graph: Graph<String> = Graph::new();
+        assert_eq!(graph.intern("AAA"), 0);
+        assert_eq!(graph.intern("BBB"), 1);
+        assert_eq!(graph.intern("AAA"), 0);
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph.id("BBB"), Some(1));
+        assert_eq!(graph.id("CCC"), None);
+        assert_eq!(graph.name(1), "BBB");
+    }
+
+    #[test]
+    fn test_follow() {
+        let graph = example();
+        let start = graph.id("AAA").unwrap();
+        let left = graph.follow(start, &Turn::Left).unwrap();
+        assert_eq!(graph.name(left), "BBB");
+        let right_left = graph
+            .follow(graph.follow(start, &Turn::Right).unwrap(), &Turn::Left)
+            .unwrap();
+        assert_eq!(graph.name(right_left), "ZZZ");
+        // leaves have no edges out of them.
+        assert_eq!(graph.follow(right_left, &Turn::Left), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let graph = example();
+        let names: Vec<&str> = graph
+            .neighbours(graph.id("BBB").unwrap())
+            .map(|id| graph.name(id).as_str())
+            .collect();
+        assert_eq!(names, vec!["DDD", "EEE"]);
+        assert_eq!(graph.node_ids().count(), 7);
+    }
+
+    fn unlabelled(edges: &[(&str, &str)]) -> Graph<String> {
+        let mut graph = Graph::new();
+        for (from, to) in edges {
+            graph.add_named_edge(*from, *to, ());
+        }
+        graph
+    }
+
+    fn names(graph: &Graph<String>, ids: &[NodeId]) -> Vec<String> {
+        ids.iter().map(|id| graph.name(*id).clone()).collect()
+    }
+
+    #[test]
+    fn test_reachable_from() {
+        let graph = example();
+        let reachable = graph.reachable_from(graph.id("CCC").unwrap());
+        let mut reachable: Vec<&str> = reachable
+            .iter()
+            .map(|id| graph.name(*id).as_str())
+            .collect();
+        reachable.sort();
+        assert_eq!(reachable, vec!["CCC", "GGG", "ZZZ"]);
+        assert_eq!(graph.reachable_from(graph.id("AAA").unwrap()).len(), 7);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = unlabelled(&[
+            ("shirt", "tie"),
+            ("tie", "jacket"),
+            ("trousers", "shoes"),
+            ("socks", "shoes"),
+            ("shirt", "jacket"),
+            ("trousers", "belt"),
+            ("belt", "jacket"),
+        ]);
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(order.len(), graph.len());
+        let position = |name: &str| order.iter().position(|id| graph.name(*id) == name).unwrap();
+        for node in graph.node_ids() {
+            for next in graph.neighbours(node) {
+                assert!(position(graph.name(node)) < position(graph.name(next)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_topological_sort_cycle() {
+        let graph = unlabelled(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "b"), ("d", "e")]);
+        let cycle = graph.topological_sort().unwrap_err();
+        let cycle = names(&graph, &cycle);
+        // the cycle can start anywhere, but must be b -> c -> d in that order.
+        let start = cycle.iter().position(|n| n == "b").unwrap();
+        let mut rotated = cycle.clone();
+        rotated.rotate_left(start);
+        assert_eq!(rotated, vec!["b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = unlabelled(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("b", "d"),
+            ("d", "e"),
+            ("e", "d"),
+            ("e", "f"),
+            ("g", "g"),
+        ]);
+        let mut components: Vec<Vec<String>> = graph
+            .strongly_connected_components()
+            .iter()
+            .map(|c| {
+                let mut c = names(&graph, c);
+                c.sort();
+                c
+            })
+            .collect();
+        // reverse topological order, so f must come before d and e, which come before a b c.
+        let position = |name: &str| {
+            components
+                .iter()
+                .position(|c| c.contains(&name.to_string()))
+                .unwrap()
+        };
+        assert!(position("f") < position("d"));
+        assert!(position("d") < position("a"));
+        components.sort();
+        assert_eq!(
+            components,
+            vec![vec!["a", "b", "c"], vec!["d", "e"], vec!["f"], vec!["g"]]
+        );
+    }
+
+    #[test]
+    fn test_scc_long_chain() {
+        // deep enough to overflow the stack if this were recursive.
+        let mut graph: Graph<usize> = Graph::new();
+        for i in 0..200_000 {
+            graph.add_named_edge(&i, &(i + 1), ());
+        }
+        graph.add_named_edge(&200_000, &0, ());
+        assert_eq!(graph.strongly_connected_components().len(), 1);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph: Graph<String, Turn> = Graph::new();
+        graph.add_named_edge("AAA", "B\"B", Turn::Left);
+        graph.add_named_edge("AAA", "AAA", Turn::Right);
+        assert_eq!(
+            graph.to_dot_with(|n| n.clone(), |l| Some(format!("{:?}", l))),
+            concat!(
+                "digraph {\n",
+                "    0 [label=\"AAA\"];\n",
+                "    1 [label=\"B\\\"B\"];\n",
+                "    0 -> 1 [label=\"Left\"];\n",
+                "    0 -> 0 [label=\"Right\"];\n",
+                "}\n",
+            )
+        );
+        let graph = unlabelled(&[("a", "b")]);
+        assert_eq!(
+            graph.to_dot_with(|n| n.clone(), |_| None),
+            "digraph {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    0 -> 1;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_undirected() {
+        let mut graph: Graph<(usize, usize), usize> = Graph::new();
+        let first = graph.intern(&(0, 0));
+        let second = graph.intern(&(0, 5));
+        graph.add_undirected_edge(first, second, 5);
+        assert_eq!(
+            graph.edges(first),
+            &[Edge {
+                to: second,
+                label: 5
+            }]
+        );
+        assert_eq!(
+            graph.edges(second),
+            &[Edge {
+                to: first,
+                label: 5
+            }]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use crate::points::UPoint;
+
+/// a rectangular 2d grid, stored row by row in a single Vec. Indexed by UPoint, with the origin in
+/// the top-left corner, just like the puzzle input it was parsed from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// builds a grid out of a list of rows. Returns none if the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        if rows.iter().any(|r| r.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_in_bounds(&self, point: &UPoint) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    /// returns the cell at a point, or none if the point is out of bounds.
+    pub fn get(&self, point: &UPoint) -> Option<&T> {
+        self.is_in_bounds(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    /// returns the cell at a point mutably, or none if the point is out of bounds.
+    pub fn get_mut(&mut self, point: &UPoint) -> Option<&mut T> {
+        if self.is_in_bounds(point) {
+            Some(&mut self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    /// every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = UPoint> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| UPoint { x, y }))
+    }
+
+    /// the rows of the grid, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a size of 0, which an empty grid would otherwise give it.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// the points directly above, below, left and right of a point that are inside the grid.
+    pub fn neighbours(&self, point: UPoint) -> impl Iterator<Item = UPoint> {
+        let (width, height) = (self.width, self.height);
+        [
+            point.y.checked_sub(1).map(|y| UPoint { x: point.x, y }),
+            Some(UPoint {
+                x: point.x,
+                y: point.y + 1,
+            }),
+            point.x.checked_sub(1).map(|x| UPoint { x, y: point.y }),
+            Some(UPoint {
+                x: point.x + 1,
+                y: point.y,
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(move |p| p.x < width && p.y < height)
+    }
+}
+
+impl<T> std::ops::Index<UPoint> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: UPoint) -> &Self::Output {
+        self.get(&index).expect("point out of bounds of grid!")
+    }
+}
+
+impl<T> std::ops::IndexMut<UPoint> for Grid<T> {
+    fn index_mut(&mut self, index: UPoint) -> &mut Self::Output {
+        self.get_mut(&index).expect("point out of bounds of grid!")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]).unwrap()
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = example();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[UPoint { x: 2, y: 1 }], 'f');
+        assert_eq!(grid.get(&UPoint { x: 3, y: 0 }), None);
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+        let rows: Vec<&[char]> = grid.rows().collect();
+        assert_eq!(rows, vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = example();
+        grid[UPoint { x: 0, y: 1 }] = 'z';
+        assert_eq!(grid.get(&UPoint { x: 0, y: 1 }), Some(&'z'));
+    }
+
+    #[test]
+    fn test_points() {
+        let grid = example();
+        let letters: String = grid.points().map(|p| grid[p]).collect();
+        assert_eq!(letters, "abcdef");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        let mut corner: Vec<UPoint> = grid.neighbours(UPoint { x: 0, y: 0 }).collect();
+        corner.sort_by_key(|p| (p.y, p.x));
+        assert_eq!(corner, vec![UPoint { x: 1, y: 0 }, UPoint { x: 0, y: 1 }]);
+        assert_eq!(grid.neighbours(UPoint { x: 1, y: 1 }).count(), 3);
+    }
+}
@@ -0,0 +1,77 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::error::AocError;
+use crate::parse::normalise;
+
+/// where a days input is kept if no other path is given, relative to where it is run from.
+pub fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/day{day:02}.txt"))
+}
+
+/// reads the puzzle input for day at runtime. path is read if its given, with "-" meaning stdin,
+/// otherwise the input is read from the default path.
+pub fn load(day: u8, path: Option<&Path>) -> Result<String, AocError> {
+    match path {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| AocError::input("stdin", &e))?;
+            Ok(input)
+        }
+        Some(path) => read(path),
+        None => read(&default_path(day)),
+    }
+}
+
+fn read(path: &Path) -> Result<String, AocError> {
+    std::fs::read_to_string(path).map_err(|e| AocError::input(path.display().to_string(), &e))
+}
+
+/// a short fingerprint of an input, for telling apart results from different inputs without
+/// storing them. The input is normalised first, so line endings dont change it. Uses FNV-1a, so
+/// unlike the std hasher it stays the same between builds.
+pub fn hash(input: &str) -> String {
+    let hash = normalise(input)
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_path() {
+        assert_eq!(default_path(7), PathBuf::from("inputs/day07.txt"));
+        assert_eq!(default_path(25), PathBuf::from("inputs/day25.txt"));
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), hash("a\r\n"));
+        assert_ne!(hash("1 2 3\n"), hash("1 2 4\n"));
+    }
+
+    #[test]
+    fn test_load_file() {
+        let path = std::env::temp_dir().join(format!("aoc_input_test_{}.txt", std::process::id()));
+        std::fs::write(&path, "1 2 3\n").unwrap();
+        assert_eq!(load(1, Some(&path)), Ok("1 2 3\n".to_string()));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = load(1, Some(Path::new("no/such/input.txt"))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "couldnt read input from no/such/input.txt: no such file, save your puzzle input there or pass --input <path>"
+        );
+    }
+}
@@ -1,4 +1,23 @@
+pub mod error;
 pub mod points;
 pub mod range;
 pub mod misc;
 pub mod distances;
+pub mod quadratic;
+pub mod rational;
+pub mod cycle;
+pub mod memo;
+pub mod graph;
+pub mod flow;
+pub mod grid;
+pub mod longest_path;
+pub mod linear;
+pub mod parse;
+pub mod bitset;
+pub mod disjoint_set;
+pub mod day;
+pub mod example;
+pub mod input;
+pub mod solution;
+#[cfg(feature = "criterion")]
+pub mod bench;
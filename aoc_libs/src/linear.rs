@@ -0,0 +1,189 @@
+use crate::rational::{Rational, RationalInt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinearSystemError {
+    /// the equations contradict each other, so there is no solution at all.
+    Inconsistent,
+    /// there are not enough independent equations to pin down every unknown, so there are
+    /// infinitely many solutions.
+    Underdetermined,
+    /// the rows of the matrix are not all the same length, or there is not one right hand side
+    /// value per row.
+    DimensionMismatch,
+}
+
+impl std::fmt::Display for LinearSystemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinearSystemError::Inconsistent => write!(f, "system of equations is inconsistent"),
+            LinearSystemError::Underdetermined => {
+                write!(f, "system of equations has infinitely many solutions")
+            }
+            LinearSystemError::DimensionMismatch => {
+                write!(f, "matrix and right hand side have mismatched dimensions")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LinearSystemError {}
+
+/// solves the system of equations Ax=b exactly, using gaussian elimination over rationals, and
+/// returns x. a is a list of rows, one per equation, each with one coefficient per unknown.
+/// There can be more equations than unknowns, as long as they all agree with each other.
+/// Coefficients can be plain integers or Rationals.
+pub fn solve<T, V>(a: &[Vec<V>], b: &[V]) -> Result<Vec<Rational<T>>, LinearSystemError>
+where
+    T: RationalInt,
+    V: Into<Rational<T>> + Copy,
+{
+    let unknowns = a.first().map_or(0, |row| row.len());
+    if a.len() != b.len() || a.iter().any(|row| row.len() != unknowns) {
+        return Err(LinearSystemError::DimensionMismatch);
+    }
+    // the augmented matrix, with b tacked on as an extra column.
+    let mut matrix: Vec<Vec<Rational<T>>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, b)| {
+            row.iter()
+                .map(|v| (*v).into())
+                .chain(std::iter::once((*b).into()))
+                .collect()
+        })
+        .collect();
+
+    // reduce to reduced row echelon form. rank counts the pivots found so far, which is also the
+    // row the next pivot goes in.
+    let mut rank = 0;
+    for column in 0..unknowns {
+        let Some(pivot) = (rank..matrix.len()).find(|r| !matrix[*r][column].is_zero()) else {
+            continue;
+        };
+        matrix.swap(rank, pivot);
+        let scale = matrix[rank][column].recip();
+        for value in matrix[rank].iter_mut() {
+            *value *= scale;
+        }
+        let pivot_row = matrix[rank].clone();
+        for (index, row) in matrix.iter_mut().enumerate() {
+            let factor = row[column];
+            if index != rank && !factor.is_zero() {
+                for (value, pivot_value) in row.iter_mut().zip(&pivot_row).skip(column) {
+                    *value -= factor * *pivot_value;
+                }
+            }
+        }
+        rank += 1;
+    }
+
+    // any leftover row now reads 0 = something, which had better be 0.
+    if matrix[rank..].iter().any(|row| !row[unknowns].is_zero()) {
+        return Err(LinearSystemError::Inconsistent);
+    }
+    if rank < unknowns {
+        return Err(LinearSystemError::Underdetermined);
+    }
+    Ok(matrix
+        .iter()
+        .take(unknowns)
+        .map(|row| row[unknowns])
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3
+        let a: Vec<Vec<i128>> = vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+        let b = [8, -11, -3];
+        assert_eq!(
+            solve(&a, &b),
+            Ok(vec![
+                Rational::from(2),
+                Rational::from(3),
+                Rational::from(-1)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_solve_fractions() {
+        // 2x = 1, 3y = 2
+        let a: Vec<Vec<i128>> = vec![vec![2, 0], vec![0, 3]];
+        assert_eq!(
+            solve(&a, &[1, 2]),
+            Ok(vec![Rational::new(1, 2), Rational::new(2, 3)])
+        );
+        let a = vec![vec![Rational::new(1_i128, 2)]];
+        assert_eq!(
+            solve(&a, &[Rational::new(1, 4)]),
+            Ok(vec![Rational::new(1, 2)])
+        );
+    }
+
+    #[test]
+    fn test_needs_row_swap() {
+        let a: Vec<Vec<i128>> = vec![vec![0, 1], vec![1, 0]];
+        assert_eq!(
+            solve(&a, &[5, 7]),
+            Ok(vec![Rational::from(7), Rational::from(5)])
+        );
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        let a: Vec<Vec<i128>> = vec![vec![1, 1], vec![2, 2]];
+        assert_eq!(solve(&a, &[1, 3]), Err(LinearSystemError::Inconsistent));
+    }
+
+    #[test]
+    fn test_underdetermined() {
+        let a: Vec<Vec<i128>> = vec![vec![1, 1], vec![2, 2]];
+        assert_eq!(solve(&a, &[1, 2]), Err(LinearSystemError::Underdetermined));
+        let a: Vec<Vec<i128>> = vec![vec![1, 1, 1]];
+        assert_eq!(solve(&a, &[1]), Err(LinearSystemError::Underdetermined));
+    }
+
+    #[test]
+    fn test_overdetermined() {
+        let a: Vec<Vec<i128>> = vec![vec![1, 0], vec![0, 1], vec![1, 1]];
+        assert_eq!(
+            solve(&a, &[1, 2, 3]),
+            Ok(vec![Rational::from(1), Rational::from(2)])
+        );
+        assert_eq!(solve(&a, &[1, 2, 4]), Err(LinearSystemError::Inconsistent));
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let a: Vec<Vec<i128>> = vec![vec![1, 0], vec![0]];
+        assert_eq!(
+            solve(&a, &[1, 2]),
+            Err(LinearSystemError::DimensionMismatch)
+        );
+        let a: Vec<Vec<i128>> = vec![vec![1, 0], vec![0, 1]];
+        assert_eq!(solve(&a, &[1]), Err(LinearSystemError::DimensionMismatch));
+    }
+
+    #[test]
+    fn test_large_magnitudes() {
+        // answers too large for an f64 to even represent exactly.
+        let x: [i128; 3] = [
+            240_000_000_000_000_017,
+            -130_000_000_000_000_003,
+            100_000_000_000_000_001,
+        ];
+        let a: Vec<Vec<i128>> = vec![vec![3, -1, 7], vec![-2, 5, 1], vec![4, 4, -9]];
+        let b: Vec<i128> = a
+            .iter()
+            .map(|row| row.iter().zip(x).map(|(a, x)| a * x).sum())
+            .collect();
+        let solution = solve(&a, &b).unwrap();
+        let solution: Vec<i128> = solution.iter().map(|r| r.to_integer().unwrap()).collect();
+        assert_eq!(solution, x);
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::graph::{Graph, NodeId};
+use crate::grid::Grid;
+use crate::points::UPoint;
+
+/// compresses a maze into a graph of its junctions. Every run of corridor cells between two
+/// junctions becomes a single edge, weighted by the number of steps it takes to walk.
+/// Junctions are the start, the end, and any open cell with more than two open neighbours.
+/// is_open says whether a tile can be walked on at all (ie is not a wall), and can_step says
+/// whether you are allowed to move from one open cell to an adjacent open one, which is where one
+/// way tiles (like slopes you can only go down) are handled. Because of those, the edges are
+/// directed: a corridor you can walk both ways gets an edge in each direction.
+/// Corridors that dead end without reaching another junction are dropped.
+pub fn compress_maze<T, O, F>(
+    grid: &Grid<T>,
+    start: UPoint,
+    end: UPoint,
+    is_open: O,
+    can_step: F,
+) -> Graph<UPoint, usize>
+where
+    O: Fn(&T) -> bool,
+    F: Fn(UPoint, UPoint) -> bool,
+{
+    let is_junction = |point: UPoint| {
+        point == start
+            || point == end
+            || grid
+                .neighbours(point)
+                .filter(|n| is_open(&grid[*n]))
+                .count()
+                > 2
+    };
+    let can_step = |from: UPoint, to: UPoint| is_open(&grid[to]) && can_step(from, to);
+
+    let mut graph = Graph::new();
+    graph.intern(&start);
+    let mut to_explore = vec![start];
+    let mut explored = HashSet::new();
+    while let Some(junction) = to_explore.pop() {
+        if !explored.insert(junction) {
+            continue;
+        }
+        let from = graph.intern(&junction);
+        for first_step in grid.neighbours(junction).filter(|n| can_step(junction, *n)) {
+            let mut previous = junction;
+            let mut current = first_step;
+            let mut steps = 1;
+            let reached = loop {
+                if is_junction(current) {
+                    break Some(current);
+                }
+                let next = grid
+                    .neighbours(current)
+                    .find(|n| *n != previous && can_step(current, *n));
+                match next {
+                    Some(next) => {
+                        previous = current;
+                        current = next;
+                        steps += 1;
+                    }
+                    None => break None,
+                }
+            };
+            if let Some(reached) = reached {
+                if reached != junction {
+                    let to = graph.intern(&reached);
+                    graph.add_edge(from, to, steps);
+                    to_explore.push(reached);
+                }
+            }
+        }
+    }
+    graph
+}
+
+/// finds the length of the longest path from start to end that never visits a node twice, using
+/// a depth first search over every possible path. Edge labels are the edge lengths.
+/// With prune set, branches that could not possibly beat the best path found so far are skipped,
+/// which is usually much faster but does a little extra bookkeeping on every step.
+/// Returns none if end cant be reached at all. Only supports graphs of up to 128 nodes, which
+/// is plenty for a compressed maze, and the search is exponential anyway.
+pub fn longest_path<N>(
+    graph: &Graph<N, usize>,
+    start: NodeId,
+    end: NodeId,
+    prune: bool,
+) -> Option<usize>
+where
+    N: Hash + Eq + Clone,
+{
+    assert!(
+        graph.len() <= 128,
+        "longest_path only supports up to 128 nodes, got {}",
+        graph.len()
+    );
+    // the most any node could add to a path is its longest edge out.
+    let best_edge: Vec<usize> = graph
+        .node_ids()
+        .map(|n| graph.edges(n).iter().map(|e| e.label).max().unwrap_or(0))
+        .collect();
+    let remaining: usize = best_edge.iter().sum();
+    let mut search = Search {
+        graph,
+        end,
+        prune,
+        best_edge,
+        best: None,
+    };
+    search.visit(
+        start,
+        1 << start,
+        0,
+        remaining - search.best_edge[start as usize],
+    );
+    search.best
+}
+
+struct Search<'a, N> {
+    graph: &'a Graph<N, usize>,
+    end: NodeId,
+    prune: bool,
+    best_edge: Vec<usize>,
+    best: Option<usize>,
+}
+
+impl<N> Search<'_, N>
+where
+    N: Hash + Eq + Clone,
+{
+    /// visited is a bitmask of the nodes already on the path, and remaining is an upper bound
+    /// on how much further the path could possibly get.
+    fn visit(&mut self, node: NodeId, visited: u128, length: usize, remaining: usize) {
+        if node == self.end {
+            self.best = self.best.max(Some(length));
+            return;
+        }
+        if self.prune
+            && self
+                .best
+                .is_some_and(|best| length + remaining + self.best_edge[node as usize] <= best)
+        {
+            return;
+        }
+        for edge in self.graph.edges(node) {
+            if visited & (1 << edge.to) == 0 {
+                self.visit(
+                    edge.to,
+                    visited | (1 << edge.to),
+                    length + edge.label,
+                    remaining - self.best_edge[edge.to as usize],
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        let input = concat!(
+            "#.#####################\n",
+            "#.......#########...###\n",
+            "#######.#########.#.###\n",
+            "###.....#.>.>.###.#.###\n",
+            "###v#####.#v#.###.#.###\n",
+            "###.>...#.#.#.....#...#\n",
+            "###v###.#.#.#########.#\n",
+            "###...#.#.#.......#...#\n",
+            "#####.#.#.#######.#.###\n",
+            "#.....#.#.#.......#...#\n",
+            "#.#####.#.#.#########v#\n",
+            "#.#...#...#...###...>.#\n",
+            "#.#.#v#######v###.###v#\n",
+            "#...#.>.#...>.>.#.###.#\n",
+            "#####v#.#.###v#.#.###.#\n",
+            "#.....#...#...#.#.#...#\n",
+            "#.#########.###.#.#.###\n",
+            "#...###...#...#...#.###\n",
+            "###.###.#.###v#####v###\n",
+            "#...#...#.#.>.>.#.>.###\n",
+            "#.###.###.#.###.#.#v###\n",
+            "#.....###...###...#...#\n",
+            "#####################.#\n",
+        );
+        Grid::from_rows(input.lines().map(|l| l.chars().collect()).collect()).unwrap()
+    }
+
+    fn slopes_are_one_way(grid: &Grid<char>) -> impl Fn(UPoint, UPoint) -> bool + '_ {
+        |from, to| match grid[from] {
+            '>' => to.x > from.x,
+            '<' => to.x < from.x,
+            'v' => to.y > from.y,
+            '^' => to.y < from.y,
+            _ => true,
+        }
+    }
+
+    fn is_open(tile: &char) -> bool {
+        *tile != '#'
+    }
+
+    const START: UPoint = UPoint { x: 1, y: 0 };
+    const END: UPoint = UPoint { x: 21, y: 22 };
+
+    #[test]
+    fn test_compress_maze() {
+        let grid = example();
+        let graph = compress_maze(&grid, START, END, is_open, |_, _| true);
+        // start, end, and the 7 junctions in between.
+        assert_eq!(graph.len(), 9);
+        let start = graph.id(&START).unwrap();
+        let first_junction = graph.edges(start)[0];
+        assert_eq!(*graph.name(first_junction.to), UPoint { x: 3, y: 5 });
+        assert_eq!(first_junction.label, 15);
+        // without one way tiles, every corridor can be walked back the way it came.
+        let edges: usize = graph.node_ids().map(|n| graph.edges(n).len()).sum();
+        assert_eq!(edges % 2, 0);
+    }
+
+    #[test]
+    fn test_one_way() {
+        let grid = example();
+        let graph = compress_maze(&grid, START, END, is_open, slopes_are_one_way(&grid));
+        let first_junction = graph.id(&UPoint { x: 3, y: 5 }).unwrap();
+        assert!(graph
+            .neighbours(first_junction)
+            .all(|n| *graph.name(n) != START));
+    }
+
+    #[test]
+    fn test_longest_path_with_slopes() {
+        let grid = example();
+        let graph = compress_maze(&grid, START, END, is_open, slopes_are_one_way(&grid));
+        let (start, end) = (graph.id(&START).unwrap(), graph.id(&END).unwrap());
+        assert_eq!(longest_path(&graph, start, end, false), Some(94));
+        assert_eq!(longest_path(&graph, start, end, true), Some(94));
+    }
+
+    #[test]
+    fn test_longest_path_without_slopes() {
+        let grid = example();
+        let graph = compress_maze(&grid, START, END, is_open, |_, _| true);
+        let (start, end) = (graph.id(&START).unwrap(), graph.id(&END).unwrap());
+        assert_eq!(longest_path(&graph, start, end, false), Some(154));
+        assert_eq!(longest_path(&graph, start, end, true), Some(154));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let mut graph: Graph<&str, usize> = Graph::new();
+        graph.add_named_edge(&"a", &"b", 3);
+        let c = graph.intern(&"c");
+        assert_eq!(longest_path(&graph, 0, c, true), None);
+        assert_eq!(longest_path(&graph, 0, 1, true), Some(3));
+    }
+}
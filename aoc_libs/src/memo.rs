@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// a cache for recursive functions. Pass the memo down through the recursion, and wrap the body
+/// of the function in get_or_compute so that each set of arguments is only ever worked out once.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Hash + Eq,
+    V: Clone,
+{
+    pub fn new() -> Memo<K, V> {
+        Memo {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// returns the cached value for key, or runs compute to produce and cache it.
+    /// compute is handed the memo back so that it can recurse.
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// forgets all cached values, and resets the hit and miss counts.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// how many calls were answered from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// how many calls had to be computed.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// the number of cached values.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<K, V> Default for Memo<K, V>
+where
+    K: Hash + Eq,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        memo.get_or_compute(n, |memo| match n {
+            0 | 1 => n,
+            n => fib(n - 1, memo) + fib(n - 2, memo),
+        })
+    }
+
+    // number of monotonic paths through a grid, with some context captured from outside.
+    fn paths(
+        point: (usize, usize),
+        blocked: &[(usize, usize)],
+        memo: &mut Memo<(usize, usize), u64>,
+    ) -> u64 {
+        memo.get_or_compute(point, |memo| {
+            if blocked.contains(&point) {
+                0
+            } else if point.0 == 0 || point.1 == 0 {
+                1
+            } else {
+                paths((point.0 - 1, point.1), blocked, memo)
+                    + paths((point.0, point.1 - 1), blocked, memo)
+            }
+        })
+    }
+
+    #[test]
+    fn test_fib() {
+        let mut memo = Memo::new();
+        assert_eq!(fib(10, &mut memo), 55);
+        assert_eq!(memo.misses(), 11);
+        assert_eq!(memo.hits(), 8);
+        assert_eq!(memo.len(), 11);
+        // far too slow without the cache.
+        assert_eq!(fib(90, &mut memo), 2880067194370816120);
+    }
+
+    #[test]
+    fn test_paths() {
+        let mut memo = Memo::new();
+        assert_eq!(paths((2, 2), &[], &mut memo), 6);
+        memo.clear();
+        assert_eq!(paths((2, 2), &[(1, 1)], &mut memo), 2);
+        assert_eq!(paths((16, 16), &[], &mut Memo::new()), 601080390);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut memo = Memo::new();
+        fib(20, &mut memo);
+        assert!(!memo.is_empty());
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.hits(), 0);
+        assert_eq!(memo.misses(), 0);
+        fib(20, &mut memo);
+        assert_eq!(memo.misses(), 21);
+    }
+}
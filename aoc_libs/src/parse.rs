@@ -0,0 +1,297 @@
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{
+        char, digit1, line_ending, multispace0, none_of, one_of, space0, space1,
+    },
+    combinator::{map_opt, not, opt, recognize},
+    error::ErrorKind,
+    multi::{many1, separated_list1},
+    sequence::{pair, preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::error::AocError;
+use crate::grid::Grid;
+
+/// cleans up input saved with different habits, so parsers only have to deal with one shape.
+/// CRLF line endings become \n, trailing whitespace is stripped from every line, and the input
+/// ends with exactly one newline, whether or not it had one before. Blank lines in the middle
+/// are kept, since they usually separate sections.
+pub fn normalise(input: &str) -> String {
+    let mut normalised = String::with_capacity(input.len() + 1);
+    for line in input.trim_end().lines() {
+        normalised.push_str(line.trim_end());
+        normalised.push('\n');
+    }
+    normalised
+}
+
+/// parses an unsigned number into any integer type. If it overflows the type, thats a hard
+/// failure rather than a normal error, so it gets reported instead of being backtracked over.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    let (rest, digits) = digit1(input)?;
+    convert(input, rest, digits)
+}
+
+/// parses a number with an optional leading + or - into any integer type. Like unsigned, fails
+/// hard if it overflows the type, or is negative and the type is unsigned.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    let (rest, number) = recognize(pair(opt(one_of("+-")), digit1))(input)?;
+    convert(input, rest, number.strip_prefix('+').unwrap_or(number))
+}
+
+fn convert<'a, T: FromStr>(input: &'a str, rest: &'a str, number: &str) -> IResult<&'a str, T> {
+    match number.parse() {
+        Ok(n) => Ok((rest, n)),
+        Err(_) => Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            ErrorKind::MapRes,
+        ))),
+    }
+}
+
+/// parses a list of numbers separated by one or more spaces, like "1  2 -3". Any leading spaces
+/// are skipped. Does not go past the end of the line.
+pub fn spaced_numbers<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    preceded(space0, separated_list1(space1, signed))(input)
+}
+
+/// parses a line like "Time:  7  15   30", with the given key before the colon, returning the
+/// numbers after it.
+pub fn key_values<'a, T: FromStr>(key: &'a str) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    preceded(pair(tag(key), char(':')), spaced_numbers)
+}
+
+/// parses a list of items separated by commas, with optional spaces after each comma, like
+/// "1, 2,3".
+pub fn comma_list<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    separated_list1(tuple((char(','), space0)), item)
+}
+
+/// parses one item per line. Does not consume the line ending after the last item.
+pub fn lines<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    separated_list1(line_ending, item)
+}
+
+/// parses an empty line, along with the end of the line before it. Spaces on the blank line are
+/// allowed.
+pub fn blank_line(input: &str) -> IResult<&str, &str> {
+    recognize(tuple((line_ending, space0, line_ending)))(input)
+}
+
+/// parses a list of sections separated by blank lines, like the maps in an almanac.
+pub fn sections<'a, O, F>(section: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    separated_list1(blank_line, section)
+}
+
+/// parses a rectangular block of characters into a Grid, converting each character with cell.
+/// Fails if cell returns none for any character, or the rows are not all the same length.
+pub fn grid<'a, T, F>(cell: F) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>>
+where
+    F: Fn(char) -> Option<T>,
+{
+    move |input| {
+        map_opt(
+            lines(terminated(
+                many1(map_opt(none_of("\r\n"), &cell)),
+                not(none_of("\r\n")),
+            )),
+            Grid::from_rows,
+        )(input)
+    }
+}
+
+/// runs parser over the whole of input, and turns a failure or any leftover input (other than
+/// trailing whitespace) into an AocError pointing at the problem.
+pub fn parse_all<'a, O, F>(input: &'a str, parser: F) -> Result<O, AocError>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    let result = terminated(parser, multispace0)(input);
+    finish(input, result, "end of input")
+}
+
+/// runs line_parser over every line of input, which each have to be parsed completely. Errors point
+/// at the right line of the whole input, rather than just being relative to the line.
+pub fn parse_lines<'a, O, F>(input: &'a str, mut line_parser: F) -> Result<Vec<O>, AocError>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    input
+        .lines()
+        .map(|line| finish(input, line_parser(line), "end of line"))
+        .collect()
+}
+
+fn finish<'a, O>(input: &'a str, result: IResult<&'a str, O>, end: &str) -> Result<O, AocError> {
+    match result {
+        Ok(("", output)) => Ok(output),
+        Ok((rest, _)) => Err(AocError::parse_at(input, rest, end)),
+        Err(e) => Err(AocError::from_nom(input, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::points::UPoint;
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(normalise("a b\nc\n\nd\n"), "a b\nc\n\nd\n");
+        assert_eq!(normalise("a b\r\nc\r\n\r\nd\r\n"), "a b\nc\n\nd\n");
+        assert_eq!(normalise("a b  \nc\t\n  \nd"), "a b\nc\n\nd\n");
+        assert_eq!(normalise("a\n\n\n"), "a\n");
+        assert_eq!(normalise("  a\n"), "  a\n");
+        assert_eq!(normalise(""), "");
+    }
+
+    #[test]
+    fn test_unsigned() {
+        assert_eq!(unsigned::<u8>("255 rest"), Ok((" rest", 255)));
+        assert!(unsigned::<u8>("256").is_err());
+        assert_eq!(unsigned::<u64>("18446744073709551615"), Ok(("", u64::MAX)));
+        assert!(unsigned::<u32>("-1").is_err());
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(signed::<i32>("-12,"), Ok((",", -12)));
+        assert_eq!(signed::<i32>("+12"), Ok(("", 12)));
+        assert_eq!(signed::<i32>("12"), Ok(("", 12)));
+        assert_eq!(signed::<u32>("+12"), Ok(("", 12)));
+        assert!(signed::<u32>("-12").is_err());
+        assert!(signed::<i8>("-129").is_err());
+        assert!(signed::<i32>("-").is_err());
+    }
+
+    #[test]
+    fn test_spaced_numbers() {
+        assert_eq!(
+            spaced_numbers::<u8>("83 86  6 31\n1 2"),
+            Ok(("\n1 2", vec![83, 86, 6, 31]))
+        );
+        assert_eq!(
+            spaced_numbers::<i32>("  -1 2 | 3"),
+            Ok((" | 3", vec![-1, 2]))
+        );
+    }
+
+    #[test]
+    fn test_key_values() {
+        assert_eq!(
+            key_values::<u64>("Time")("Time:      7  15   30\nDistance: 9"),
+            Ok(("\nDistance: 9", vec![7, 15, 30]))
+        );
+        assert_eq!(
+            key_values::<u64>("seeds")("seeds: 79 14"),
+            Ok(("", vec![79, 14]))
+        );
+        assert!(key_values::<u64>("Time")("Distance: 9").is_err());
+    }
+
+    #[test]
+    fn test_comma_list() {
+        assert_eq!(
+            comma_list(signed::<i64>)("19, 13,30 @ -2"),
+            Ok((" @ -2", vec![19, 13, 30]))
+        );
+        assert_eq!(
+            comma_list(tag("red"))("red, red; blue"),
+            Ok(("; blue", vec!["red", "red"]))
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(
+            lines(spaced_numbers::<u32>)("1 2\r\n3 4\n5\n"),
+            Ok(("\n", vec![vec![1, 2], vec![3, 4], vec![5]]))
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "1 2\n3\n\n4\n  \n5 6\n";
+        assert_eq!(
+            sections(lines(spaced_numbers::<u32>))(input),
+            Ok((
+                "\n",
+                vec![vec![vec![1, 2], vec![3]], vec![vec![4]], vec![vec![5, 6]]]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let cell = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        let (rest, parsed) = grid(cell)("#..\n.#.\n\nnext").unwrap();
+        assert_eq!(rest, "\n\nnext");
+        assert_eq!(parsed.width(), 3);
+        assert_eq!(parsed.height(), 2);
+        assert!(parsed[UPoint { x: 1, y: 1 }]);
+        assert!(!parsed[UPoint { x: 2, y: 1 }]);
+        assert!(grid(cell)("#..\n.#\n").is_err());
+        assert!(grid(cell)("#x.\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(
+            parse_all("1 2 3\n\n", spaced_numbers::<u8>),
+            Ok(vec![1, 2, 3])
+        );
+        let err = parse_all("1 2 3\n4", spaced_numbers::<u8>).unwrap_err();
+        let AocError::Parse {
+            line,
+            column,
+            expected,
+            ..
+        } = err
+        else {
+            panic!("expected a parse error")
+        };
+        assert_eq!((line, column), (2, 1));
+        assert_eq!(expected, "end of input");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines("1 2\n3\n", spaced_numbers::<u8>),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        let AocError::Parse { line, column, .. } =
+            parse_lines("1 2\n3 999\n", spaced_numbers::<u8>).unwrap_err()
+        else {
+            panic!("expected a parse error")
+        };
+        assert_eq!((line, column), (2, 3));
+        let AocError::Parse {
+            line,
+            column,
+            expected,
+            ..
+        } = parse_lines("1 2\n3 x\n", spaced_numbers::<u8>).unwrap_err()
+        else {
+            panic!("expected a parse error")
+        };
+        assert_eq!((line, column), (2, 2));
+        assert_eq!(expected, "end of line");
+    }
+}
@@ -0,0 +1,228 @@
+use std::ops::Range;
+
+/// the integer square root of n, rounded down. Exact for every u128 (and so for anything that
+/// casts losslessly into one).
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // start from a power of two that is guaranteed to be >= sqrt(n). Newtons method then
+    // decreases monotonically until it hits the floor of the root.
+    let mut x = 1_u128 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// returns the range of integers x for which a*x^2 + b*x + c > 0.
+/// The parabola must open downwards (a < 0), as otherwise the set of solutions is unbounded.
+/// If the parabola never rises above 0, an empty range is returned.
+/// The answer is always exact and nothing overflows: its none if the range, or the bounds on the
+/// way to it, dont fit in an i128, which cant happen when the coefficients all fit in a u64.
+pub fn positive_interval(a: i128, b: i128, c: i128) -> Option<Range<i128>> {
+    assert!(a < 0, "parabola must open downwards, got a = {}", a);
+    // with A = -a, f(x) > 0 rearranges to (2Ax - b)^2 < b^2 + 4Ac. (2Ax - b)^2 is always b^2
+    // mod 4, so thats the same as (2Ax - b)^2 < 4q with q = floor(b^2 / 4) + Ac, which unlike
+    // the discriminant always fits in 256 bits.
+    let big_a = a.unsigned_abs();
+    let k = b.unsigned_abs() / 2;
+    let quarter_b2 = U256::mul(k, k).checked_add(U256::from(k * (b.unsigned_abs() % 2)))?;
+    let ac = U256::mul(big_a, c.unsigned_abs());
+    let q = if c >= 0 {
+        quarter_b2.checked_add(ac)?
+    } else if ac < quarter_b2 {
+        quarter_b2.sub(ac)
+    } else {
+        return Some(0..0);
+    };
+    if q == U256::from(0) {
+        return Some(0..0);
+    }
+    // the largest m with m^2 < 4q, so then -m <= 2Ax - b <= m.
+    let t = q.isqrt();
+    let t2 = U256::mul(t, t);
+    let two_t = t.checked_mul(2)?;
+    let m = if t2 == q {
+        two_t - 1
+    } else if t2.checked_add(U256::from(t))? >= q {
+        two_t
+    } else {
+        two_t.checked_add(1)?
+    };
+    let m = i128::try_from(m).ok()?;
+    let denominator = a.checked_mul(-2)?;
+    let lower = ceil_div(b.checked_sub(m)?, denominator);
+    let upper = b.checked_add(m)?.div_euclid(denominator);
+    Some(if lower > upper {
+        lower..lower
+    } else {
+        lower..upper.checked_add(1)?
+    })
+}
+
+/// an unsigned 256 bit number, with just enough arithmetic for positive_interval. hi comes first
+/// so the derived ordering is the numeric one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct U256 {
+    hi: u128,
+    lo: u128,
+}
+
+impl U256 {
+    fn from(n: u128) -> U256 {
+        U256 { hi: 0, lo: n }
+    }
+
+    /// the full product of x and y, which always fits.
+    fn mul(x: u128, y: u128) -> U256 {
+        const LOW: u128 = u64::MAX as u128;
+        let (x1, x0) = (x >> 64, x & LOW);
+        let (y1, y0) = (y >> 64, y & LOW);
+        let (mid, mid_carry) = (x1 * y0).overflowing_add(x0 * y1);
+        let (lo, carry) = (x0 * y0).overflowing_add(mid << 64);
+        let hi = x1 * y1 + (mid >> 64) + ((mid_carry as u128) << 64) + carry as u128;
+        U256 { hi, lo }
+    }
+
+    fn checked_add(self, other: U256) -> Option<U256> {
+        let (lo, carry) = self.lo.overflowing_add(other.lo);
+        let hi = self.hi.checked_add(other.hi)?.checked_add(carry as u128)?;
+        Some(U256 { hi, lo })
+    }
+
+    /// self - other, where other cant be bigger than self.
+    fn sub(self, other: U256) -> U256 {
+        let (lo, borrow) = self.lo.overflowing_sub(other.lo);
+        U256 {
+            hi: self.hi - other.hi - borrow as u128,
+            lo,
+        }
+    }
+
+    /// the integer square root, rounded down. Only called on numbers below 2^256, whose root
+    /// fits in a u128.
+    fn isqrt(self) -> u128 {
+        if self.hi == 0 {
+            return isqrt(self.lo);
+        }
+        // a binary search for the largest root whose square isnt too big.
+        let (mut low, mut high) = (0_u128, u128::MAX);
+        while low < high {
+            let mid = low + (high - low) / 2 + 1;
+            if U256::mul(mid, mid) <= self {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        low
+    }
+}
+
+/// division rounding towards positive infinity, for a positive denominator.
+fn ceil_div(numerator: i128, denominator: i128) -> i128 {
+    -(-numerator).div_euclid(denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(3), 1);
+        assert_eq!(isqrt(4), 2);
+        assert_eq!(isqrt(99), 9);
+        assert_eq!(isqrt(100), 10);
+        assert_eq!(isqrt(u64::MAX as u128), u32::MAX as u128);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        let big = 999_999_999_999_999_999_u128;
+        assert_eq!(isqrt(big * big), big);
+        assert_eq!(isqrt(big * big - 1), big - 1);
+    }
+
+    #[test]
+    fn test_positive_interval() {
+        // -x^2 + 7x - 9 > 0, ie the 7ms race with a record of 9.
+        assert_eq!(positive_interval(-1, 7, -9), Some(2..6));
+        // roots land exactly on integers, which must be excluded.
+        assert_eq!(positive_interval(-1, 30, -200), Some(11..20));
+        assert_eq!(positive_interval(-1, 71530, -940200), Some(14..71517));
+        assert_eq!(positive_interval(-2, 0, 8), Some(-1..2));
+        assert_eq!(positive_interval(-3, 10, 0), Some(1..4));
+    }
+
+    #[test]
+    fn test_positive_interval_empty() {
+        // touches zero at x=2 but never rises above it.
+        assert!(positive_interval(-1, 4, -4).unwrap().is_empty());
+        assert!(positive_interval(-1, 2, -5).unwrap().is_empty());
+        // positive only between two integers.
+        assert!(positive_interval(-4, 6, -2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_positive_interval_large() {
+        // a 15 digit race time, where f64 has already started losing precision.
+        let time: i128 = 123_456_789_012_345;
+        let record: i128 = time * time / 4 - 1_000_000_000_000_007;
+        let range = positive_interval(-1, time, -record).unwrap();
+        assert!(!range.is_empty());
+        assert!(range.start * (time - range.start) > record);
+        assert!((range.start - 1) * (time - range.start + 1) <= record);
+        assert!((range.end - 1) * (time - range.end + 1) > record);
+        assert!(range.end * (time - range.end) <= record);
+    }
+
+    #[test]
+    fn test_positive_interval_brute_force() {
+        for a in -4..0 {
+            for b in -12..=12 {
+                for c in -12..=12 {
+                    let expected: Vec<i128> =
+                        (-20..=20).filter(|x| a * x * x + b * x + c > 0).collect();
+                    let range = positive_interval(a, b, c).unwrap();
+                    assert_eq!(range.collect::<Vec<_>>(), expected, "{a}x^2 + {b}x + {c}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_positive_interval_u64() {
+        // the longest race there can be: every charge but none and all of it wins.
+        let max = u64::MAX as i128;
+        assert_eq!(positive_interval(-1, max, 0), Some(1..max));
+        assert_eq!(positive_interval(-1, max, -max), Some(2..max - 1));
+        // a discriminant over 2^128, which takes the wide square root.
+        let b = 1_i128 << 100;
+        assert_eq!(positive_interval(-1, b, -1), Some(1..b));
+        // x(b - 3x) > max holds from x = 1 up to where b - 3x is still positive. 2^100 is one
+        // more than a multiple of 3, so the last one is (b - 1) / 3, where b - 3x is 1.
+        assert_eq!(b % 3, 1);
+        assert_eq!(positive_interval(-3, b, -max), Some(1..(b - 1) / 3 + 1));
+    }
+
+    #[test]
+    fn test_positive_interval_extreme() {
+        // whatever the coefficients, it gives an answer or none, but never panics.
+        let extremes = [i128::MIN, i128::MIN + 1, -1, 0, 1, i128::MAX];
+        for a in [i128::MIN, i128::MIN + 1, -2, -1] {
+            for b in extremes {
+                for c in extremes {
+                    positive_interval(a, b, c);
+                }
+            }
+        }
+        assert_eq!(
+            positive_interval(-1, i128::MAX / 2, 0),
+            Some(1..i128::MAX / 2)
+        );
+    }
+}
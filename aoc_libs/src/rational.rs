@@ -0,0 +1,418 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// the integer types a Rational can be built out of. Implemented for all the signed primitives.
+pub trait RationalInt:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + From<i8>
+{
+}
+
+impl<T> RationalInt for T where
+    T: Copy
+        + Ord
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+        + Neg<Output = T>
+        + From<i8>
+{
+}
+
+fn gcd<T: RationalInt>(a: T, b: T) -> T {
+    let zero = T::from(0);
+    let mut a = if a < zero { -a } else { a };
+    let mut b = if b < zero { -b } else { b };
+    while b != zero {
+        let tmp = b;
+        b = a % b;
+        a = tmp;
+    }
+    a
+}
+
+/// an exact fraction. Always kept in lowest terms with a positive denominator, so two equal
+/// fractions are always structurally equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: RationalInt> Rational<T> {
+    /// creates a new fraction, reducing it to lowest terms.
+    /// Panics if the denominator is zero, just like integer division would.
+    pub fn new(numerator: T, denominator: T) -> Rational<T> {
+        let zero = T::from(0);
+        assert!(denominator != zero, "rational with a zero denominator");
+        let divisor = gcd(numerator, denominator);
+        let (numerator, denominator) = if denominator < zero {
+            (-(numerator / divisor), -(denominator / divisor))
+        } else {
+            (numerator / divisor, denominator / divisor)
+        };
+        Rational {
+            numerator,
+            denominator,
+        }
+    }
+
+    pub fn zero() -> Rational<T> {
+        T::from(0).into()
+    }
+
+    pub fn one() -> Rational<T> {
+        T::from(1).into()
+    }
+
+    pub fn numerator(&self) -> T {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> T {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == T::from(0)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == T::from(1)
+    }
+
+    /// returns the fraction as an integer, or none if it has a fractional part.
+    pub fn to_integer(&self) -> Option<T> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    /// the largest integer less than or equal to the fraction.
+    pub fn floor(&self) -> T {
+        let quotient = self.numerator / self.denominator;
+        if self.numerator % self.denominator < T::from(0) {
+            quotient - T::from(1)
+        } else {
+            quotient
+        }
+    }
+
+    /// the smallest integer greater than or equal to the fraction.
+    pub fn ceil(&self) -> T {
+        -(-*self).floor()
+    }
+
+    /// one over the fraction. Panics if the fraction is zero.
+    pub fn recip(&self) -> Rational<T> {
+        Rational::new(self.denominator, self.numerator)
+    }
+
+    pub fn abs(&self) -> Rational<T> {
+        if self.numerator < T::from(0) {
+            -*self
+        } else {
+            *self
+        }
+    }
+}
+
+impl<T: RationalInt> From<T> for Rational<T> {
+    fn from(value: T) -> Self {
+        Rational {
+            numerator: value,
+            denominator: T::from(1),
+        }
+    }
+}
+
+impl<T: RationalInt> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: RationalInt> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // cross multiplying would overflow for big fractions, so compare the integer parts and
+        // then the leftovers, which are flipped over each round like in euclids algorithm.
+        let zero = T::from(0);
+        let (mut a, mut b) = (self.numerator, self.denominator);
+        let (mut c, mut d) = (other.numerator, other.denominator);
+        let mut flipped = false;
+        loop {
+            let (first, a_rem) = floor_rem(a, b);
+            let (second, c_rem) = floor_rem(c, d);
+            let ordering = match (first.cmp(&second), a_rem == zero, c_rem == zero) {
+                (Ordering::Equal, true, true) => Ordering::Equal,
+                (Ordering::Equal, true, false) => Ordering::Less,
+                (Ordering::Equal, false, true) => Ordering::Greater,
+                (Ordering::Equal, false, false) => {
+                    // a_rem/b against c_rem/d, both between 0 and 1, is d/c_rem against b/a_rem.
+                    (a, b, c, d) = (b, a_rem, d, c_rem);
+                    flipped = !flipped;
+                    continue;
+                }
+                (ordering, _, _) => ordering,
+            };
+            return if flipped {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+        }
+    }
+}
+
+/// numerator / denominator rounded down, and whats left over, which is never negative. The
+/// denominator has to be positive.
+fn floor_rem<T: RationalInt>(numerator: T, denominator: T) -> (T, T) {
+    let (quotient, remainder) = (numerator / denominator, numerator % denominator);
+    if remainder < T::from(0) {
+        (quotient - T::from(1), remainder + denominator)
+    } else {
+        (quotient, remainder)
+    }
+}
+
+impl<T: RationalInt + Display> Display for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl<T: RationalInt> std::ops::Add for Rational<T> {
+    type Output = Rational<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // scale by the lcm of the denominators rather than their product to stave off overflow.
+        let divisor = gcd(self.denominator, rhs.denominator);
+        Rational::new(
+            self.numerator * (rhs.denominator / divisor)
+                + rhs.numerator * (self.denominator / divisor),
+            self.denominator / divisor * rhs.denominator,
+        )
+    }
+}
+
+impl<T: RationalInt> std::ops::AddAssign for Rational<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<T: RationalInt> std::ops::Sub for Rational<T> {
+    type Output = Rational<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T: RationalInt> std::ops::SubAssign for Rational<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<T: RationalInt> std::ops::Mul for Rational<T> {
+    type Output = Rational<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // cancel common factors before multiplying, again to stave off overflow.
+        let first = gcd(self.numerator, rhs.denominator);
+        let second = gcd(rhs.numerator, self.denominator);
+        if first == T::from(0) || second == T::from(0) {
+            return Rational::zero();
+        }
+        Rational::new(
+            (self.numerator / first) * (rhs.numerator / second),
+            (self.denominator / second) * (rhs.denominator / first),
+        )
+    }
+}
+
+impl<T: RationalInt> std::ops::MulAssign for Rational<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<T: RationalInt> std::ops::Div for Rational<T> {
+    type Output = Rational<T>;
+
+    /// panics when dividing by zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.recip()
+    }
+}
+
+impl<T: RationalInt> std::ops::DivAssign for Rational<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<T: RationalInt> std::ops::Neg for Rational<T> {
+    type Output = Rational<T>;
+
+    fn neg(self) -> Self::Output {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalisation() {
+        let half = Rational::new(1_i128, 2);
+        assert_eq!(Rational::new(2, 4), half);
+        assert_eq!(Rational::new(-3, -6), half);
+        assert_eq!(Rational::new(3, -6), -half);
+        assert_eq!(Rational::new(3, -6).denominator(), 2);
+        assert_eq!(Rational::new(0, -5), Rational::zero());
+        assert_eq!(Rational::new(0, -5).denominator(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_denominator() {
+        Rational::new(1_i128, 0);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut fractions = vec![
+            Rational::new(1_i128, 2),
+            Rational::new(-7, 3),
+            Rational::new(2, 3),
+            Rational::from(0),
+            Rational::new(-1, 2),
+        ];
+        fractions.sort();
+        assert_eq!(
+            fractions,
+            vec![
+                Rational::new(-7, 3),
+                Rational::new(-1, 2),
+                Rational::from(0),
+                Rational::new(1, 2),
+                Rational::new(2, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ordering_near_max() {
+        let max = i64::MAX;
+        // cross multiplying any of these overflows an i64.
+        let below = Rational::new(max - 2, max - 1);
+        let above = Rational::new(max - 1, max);
+        assert!(below < above);
+        assert!(-above < -below);
+        assert!(Rational::new(max, max - 1) < Rational::new(max - 1, max - 2));
+        assert!(Rational::new(max, 2) < Rational::new(max - 1, 1));
+        assert_eq!(above.cmp(&above), Ordering::Equal);
+
+        // and against the same comparison done in an i128, where it cant overflow.
+        let values = [
+            -max,
+            -max + 1,
+            -(max / 2),
+            -3,
+            -1,
+            1,
+            2,
+            max / 3,
+            max - 1,
+            max,
+        ];
+        let positive = [1, 2, 3, max / 3, max - 1, max];
+        for a in values {
+            for b in positive {
+                for c in values {
+                    for d in positive {
+                        let (first, second) = (Rational::new(a, b), Rational::new(c, d));
+                        let wide = (first.numerator() as i128 * second.denominator() as i128)
+                            .cmp(&(second.numerator() as i128 * first.denominator() as i128));
+                        assert_eq!(first.cmp(&second), wide, "{first} against {second}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let third = Rational::new(1_i128, 3);
+        let half = Rational::new(1_i128, 2);
+        assert_eq!(third + half, Rational::new(5, 6));
+        assert_eq!(third - half, Rational::new(-1, 6));
+        assert_eq!(third * half, Rational::new(1, 6));
+        assert_eq!(third / half, Rational::new(2, 3));
+        assert_eq!(half * Rational::zero(), Rational::zero());
+        let mut acc = Rational::zero();
+        for _ in 0..6 {
+            acc += third;
+        }
+        assert_eq!(acc, Rational::from(2));
+        acc -= half;
+        acc *= Rational::from(2);
+        acc /= Rational::from(3);
+        assert_eq!(acc, Rational::one());
+    }
+
+    #[test]
+    fn test_no_float_error() {
+        // 0.1 + 0.2 != 0.3 in floats.
+        let tenth = Rational::new(1_i128, 10);
+        assert_eq!(tenth + Rational::new(2, 10), Rational::new(3, 10));
+        // large denominators stay exact as long as the reduced result fits.
+        let big = Rational::new(1_i128, 1_000_000_000_000_000_000);
+        assert_eq!(
+            big * Rational::from(3_000_000_000_000_000_000),
+            Rational::from(3)
+        );
+    }
+
+    #[test]
+    fn test_to_integer() {
+        assert_eq!(Rational::new(10_i128, 5).to_integer(), Some(2));
+        assert_eq!(Rational::new(10_i128, 4).to_integer(), None);
+        assert_eq!(Rational::new(-10_i128, 5).to_integer(), Some(-2));
+    }
+
+    #[test]
+    fn test_floor_ceil() {
+        assert_eq!(Rational::new(7_i128, 2).floor(), 3);
+        assert_eq!(Rational::new(7_i128, 2).ceil(), 4);
+        assert_eq!(Rational::new(-7_i128, 2).floor(), -4);
+        assert_eq!(Rational::new(-7_i128, 2).ceil(), -3);
+        assert_eq!(Rational::new(6_i128, 2).floor(), 3);
+        assert_eq!(Rational::new(6_i128, 2).ceil(), 3);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Rational::new(6_i128, 4).to_string(), "3/2");
+        assert_eq!(Rational::new(-6_i128, 3).to_string(), "-2");
+    }
+}
@@ -0,0 +1,276 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::day::Part;
+use crate::error::AocError;
+
+/// the answer to one part of a puzzle. Nearly always a number, but some puzzles want text, like
+/// letters drawn on a screen.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// the answer to one part, and how long it took to work out from the parsed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// the results of solving some parts of a day once, with the time spent parsing kept separate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+impl Run {
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().map(|p| p.time).sum::<Duration>()
+    }
+}
+
+/// one days puzzle, so runners, tests and benchmarks can treat every day the same way.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    /// for days where part 2 reads the input differently. None, the default, means part 2 shares
+    /// the input parsed for part 1.
+    fn parse_part2(_input: &str) -> Option<Result<Self::Input, AocError>> {
+        None
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// parses input and solves part 1 from it.
+    fn solve_part1(input: &str) -> Result<Answer, AocError> {
+        Ok(Self::part1(&Self::parse(input)?))
+    }
+
+    /// parses input for part 2 and solves part 2 from it.
+    fn solve_part2(input: &str) -> Result<Answer, AocError> {
+        let parsed = match Self::parse_part2(input) {
+            Some(parsed) => parsed?,
+            None => Self::parse(input)?,
+        };
+        Ok(Self::part2(&parsed))
+    }
+
+    /// solves each of parts in turn, timing the parsing and each part separately. The input is
+    /// only parsed once if both parts share it.
+    fn run(input: &str, parts: &[Part]) -> Result<Run, AocError> {
+        let mut parse = Duration::ZERO;
+        let mut shared = None;
+        let mut runs = Vec::new();
+        for &part in parts {
+            let start = Instant::now();
+            let own = match part {
+                Part::One => None,
+                Part::Two => Self::parse_part2(input).transpose()?,
+            };
+            if own.is_none() && shared.is_none() {
+                shared = Some(Self::parse(input)?);
+            }
+            parse += start.elapsed();
+            let parsed = own.as_ref().or(shared.as_ref()).unwrap();
+
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => Self::part1(parsed),
+                Part::Two => Self::part2(parsed),
+            };
+            runs.push(PartRun {
+                part,
+                answer,
+                time: start.elapsed(),
+            });
+        }
+        Ok(Run { parse, parts: runs })
+    }
+}
+
+/// solves both parts of input with S, sharing one parsed input between every run: part 1 first,
+/// then part 2 first, then both at once on separate threads. Panics if the answers dont all agree,
+/// which would mean a part is changing its input. Returns the answers, so tests can check them
+/// too.
+pub fn assert_parts_independent<S>(input: &str) -> (Answer, Answer)
+where
+    S: Solution,
+    S::Input: Sync,
+{
+    let input1 = S::parse(input).expect("input should parse for part 1");
+    let own_input2 = S::parse_part2(input).map(|i| i.expect("input should parse for part 2"));
+    let input2 = own_input2.as_ref().unwrap_or(&input1);
+
+    let forwards = (S::part1(&input1), S::part2(input2));
+    let backwards = {
+        let two = S::part2(input2);
+        (S::part1(&input1), two)
+    };
+    let concurrent = std::thread::scope(|scope| {
+        let one = scope.spawn(|| S::part1(&input1));
+        let two = scope.spawn(|| S::part2(input2));
+        (one.join().unwrap(), two.join().unwrap())
+    });
+
+    assert_eq!(
+        forwards, backwards,
+        "running part 2 first changed the answers"
+    );
+    assert_eq!(
+        forwards, concurrent,
+        "running the parts at once changed the answers"
+    );
+    forwards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>, AocError> {
+            input
+                .split_whitespace()
+                .map(|n| {
+                    n.parse()
+                        .map_err(|_| AocError::parse_at(input, n, "a number"))
+                })
+                .collect()
+        }
+
+        fn parse_part2(input: &str) -> Option<Result<Vec<u32>, AocError>> {
+            Some(Self::parse(input).map(|n| n.into_iter().rev().collect()))
+        }
+
+        fn part1(input: &Vec<u32>) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+
+        fn part2(input: &Vec<u32>) -> Answer {
+            input
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<String>()
+                .into()
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Sum::solve_part1("1 2 3"), Ok(Answer::Int(6)));
+        assert_eq!(
+            Sum::solve_part2("1 2 3"),
+            Ok(Answer::Text("321".to_string()))
+        );
+        assert!(Sum::solve_part1("1 x 3").is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let run = Sum::run("1 2 3", &Part::BOTH).unwrap();
+        let answers: Vec<_> = run
+            .parts
+            .iter()
+            .map(|p| (p.part, p.answer.clone()))
+            .collect();
+        assert_eq!(
+            answers,
+            vec![
+                (Part::One, Answer::Int(6)),
+                (Part::Two, Answer::Text("321".to_string()))
+            ]
+        );
+        assert!(run.total() >= run.parse);
+        let run = Counter::run("", &[Part::Two]).unwrap();
+        assert_eq!(run.parts.len(), 1);
+        assert!(Sum::run("1 x", &[Part::Two]).is_err());
+    }
+
+    #[test]
+    fn test_parts_independent() {
+        assert_eq!(
+            assert_parts_independent::<Sum>("1 2 3"),
+            (Answer::Int(6), Answer::Text("321".to_string()))
+        );
+    }
+
+    /// sneaks a counter into its input, so each run of part 1 gives a different answer.
+    struct Counter;
+
+    impl Solution for Counter {
+        type Input = AtomicU32;
+
+        fn parse(_: &str) -> Result<AtomicU32, AocError> {
+            Ok(AtomicU32::new(0))
+        }
+
+        fn part1(input: &AtomicU32) -> Answer {
+            input.fetch_add(1, Ordering::SeqCst).into()
+        }
+
+        fn part2(_: &AtomicU32) -> Answer {
+            0.into()
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "running part 2 first changed the answers")]
+    fn test_parts_not_independent() {
+        assert_parts_independent::<Counter>("");
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+        assert_eq!(Answer::from("EHZRPFJC").to_string(), "EHZRPFJC");
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_libs::bench::bench_day;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day01::Day01;
+
+fn bench(c: &mut Criterion) {
+    bench_day::<Day01>(c, 1);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
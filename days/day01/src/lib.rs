@@ -0,0 +1,44 @@
+pub mod parse;
+pub mod part1;
+pub mod part2;
+
+use aoc_libs::day::Day;
+use aoc_libs::error::AocError;
+use aoc_libs::solution::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::parse(input)
+    }
+
+    fn parse_part2(input: &str) -> Option<Result<Self::Input, AocError>> {
+        Some(parse::parse_english(input))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::part2(input).into()
+    }
+}
+
+pub const DAY: Day = Day::new::<Day01>(1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_libs::example;
+    use aoc_libs::solution::assert_parts_independent;
+
+    #[test]
+    fn test_parts_independent() {
+        let example = example::load(1, "example").unwrap();
+        assert_parts_independent::<Day01>(&example.input);
+    }
+}
@@ -1,3 +1,6 @@
+use aoc_libs::error::AocError;
+use aoc_libs::parse::normalise;
+
 static REPLACEMENT_STRINGS: [(&str, &str); 10] = [
     ("zero", "zero0zero"),
     ("one", "one1one"),
@@ -11,67 +14,84 @@ static REPLACEMENT_STRINGS: [(&str, &str); 10] = [
     ("nine", "nine9nine"),
 ];
 
-pub fn parse_english(input: &str) -> Vec<Vec<char>> {
+pub fn parse_english(input: &str) -> Result<Vec<Vec<char>>, AocError> {
+    let input = &normalise(input);
     input
-        .to_string()
         .lines()
         .map(|l| {
             let mut ret = l.to_string();
             for (string, replacement) in REPLACEMENT_STRINGS.iter() {
                 ret = ret.replace(string, replacement)
             }
-            ret
+            digits(input, l, &ret, "a digit or a spelled out digit")
         })
-        .map(|l| l.chars().filter(|c| c.is_ascii_digit()).collect())
         .collect()
 }
 
-pub fn parse(input: &str) -> Vec<Vec<char>> {
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, AocError> {
+    let input = &normalise(input);
     input
         .lines()
-        .map(|l| l.chars().filter(|c| c.is_ascii_digit()).collect())
+        .map(|l| digits(input, l, l, "a digit"))
         .collect()
 }
 
+/// the digits in text, which came from line. Every line needs at least one, or there is no
+/// calibration value to get out of it.
+fn digits(input: &str, line: &str, text: &str, expected: &str) -> Result<Vec<char>, AocError> {
+    let digits: Vec<char> = text.chars().filter(|c| c.is_ascii_digit()).collect();
+    if digits.is_empty() {
+        return Err(AocError::parse_at(input, line, expected));
+    }
+    Ok(digits)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_libs::example;
 
     #[test]
     fn test_parse() {
-        let input = concat!("1abc2\n", "pqr3stu8vwx\n", "a1b2c3d4e5f\n", "treb7uchet\n",);
-        assert_eq!(
-            parse(input),
-            vec![
-                vec!['1', '2',],
-                vec!['3', '8',],
-                vec!['1', '2', '3', '4', '5'],
-                vec!['7']
-            ]
-        );
+        let example = example::load(1, "example").unwrap();
+        let parsed = parse(&example.input).unwrap();
+        assert_eq!(parsed.len(), 4);
+        assert_eq!(parsed[0], vec!['1', '2']);
+        assert_eq!(parsed[3], vec!['7']);
     }
     #[test]
     fn test_english() {
-        let input = concat!(
-            "two1nine\n",
-            "eightwothree\n",
-            "abcone2threexyz\n",
-            "xtwone3four\n",
-            "4nineeightseven2\n",
-            "zoneight234\n",
-            "7pqrstsixteen\n",
-        );
+        let example = example::load(1, "part2").unwrap();
+        let parsed = parse_english(&example.input).unwrap();
+        assert_eq!(parsed.len(), 7);
+        assert_eq!(parsed[0], vec!['2', '1', '9']);
+        // overlapping words like eightwo count as both.
+        assert_eq!(parsed[1], vec!['8', '2', '3']);
+        assert_eq!(parsed[5], vec!['1', '8', '2', '3', '4']);
+    }
+
+    #[test]
+    fn test_no_digits() {
+        let input = concat!("1abc2\n", "pqrstu\n");
+        let err = parse(input).unwrap_err();
         assert_eq!(
-            parse_english(input),
-            vec![
-                vec!['2', '1', '9',],
-                vec!['8', '2', '3',],
-                vec!['1', '2', '3',],
-                vec!['2', '1', '3', '4'],
-                vec!['4', '9', '8', '7', '2'],
-                vec!['1', '8', '2', '3', '4'],
-                vec!['7', '6'],
-            ]
+            err.to_string(),
+            concat!(
+                "parse error on line 2, column 1: expected a digit\n",
+                "  |\n",
+                "2 | pqrstu\n",
+                "  | ^^^^^^"
+            )
         );
+        assert!(parse_english("pqrsixstu\n").is_ok());
+        assert!(parse_english("pqrstu\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_messy_input() {
+        let input = concat!("1abc2\n", "pqr3stu8vwx\n", "two1nine\n");
+        let messy = concat!("1abc2\r\n", "pqr3stu8vwx  \r\n", "two1nine");
+        assert_eq!(parse(messy).unwrap(), parse(input).unwrap());
+        assert_eq!(parse_english(messy).unwrap(), parse_english(input).unwrap());
     }
 }
@@ -1,7 +1,8 @@
+use aoc_libs::error::AocError;
+use aoc_libs::parse::{comma_list, normalise, parse_lines, unsigned};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::{self},
     combinator::map,
     multi::separated_list1,
     sequence::{preceded, terminated},
@@ -39,16 +40,13 @@ impl Game {
     }
 
     fn parse_game_id(input: &str) -> IResult<&str, u8> {
-        terminated(
-            preceded(tag("Game "), nom::character::complete::u8),
-            tag(": "),
-        )(input)
+        terminated(preceded(tag("Game "), unsigned), tag(": "))(input)
     }
 
     fn parse_colour_set(input: &str) -> IResult<&str, Colour> {
-        let red = terminated(character::complete::u8, tag(" red"));
-        let green = terminated(character::complete::u8, tag(" green"));
-        let blue = terminated(character::complete::u8, tag(" blue"));
+        let red = terminated(unsigned, tag(" red"));
+        let green = terminated(unsigned, tag(" green"));
+        let blue = terminated(unsigned, tag(" blue"));
         alt((
             map(red, Colour::Red),
             map(green, Colour::Green),
@@ -57,7 +55,7 @@ impl Game {
     }
 
     fn parse_handful(input: &str) -> IResult<&str, Handful> {
-        let (input, colours) = separated_list1(tag(", "), Self::parse_colour_set)(input)?;
+        let (input, colours) = comma_list(Self::parse_colour_set)(input)?;
         let mut red = 0;
         let mut green = 0;
         let mut blue = 0;
@@ -93,123 +91,33 @@ enum Colour {
     Blue(u8),
 }
 
-pub fn parse(input: &str) -> Vec<Game> {
-    input.lines().map(|l| Game::parse(l).unwrap().1).collect()
+pub fn parse(input: &str) -> Result<Vec<Game>, AocError> {
+    let input = &normalise(input);
+    parse_lines(input, Game::parse)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_libs::example;
 
     #[test]
     fn test_parse() {
-        let input = concat!(
-            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n",
-            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n",
-            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n",
-            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n",
-            "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n",
-        );
+        let example = example::load(2, "example").unwrap();
+        let games = parse(&example.input).unwrap();
+        assert_eq!(games.len(), 5);
+        assert_eq!(games[0].id, 1);
         assert_eq!(
-            parse(input),
-            vec![
-                Game {
-                    id: 1,
-                    handfuls: vec![
-                        Handful {
-                            red: 4,
-                            green: 0,
-                            blue: 3
-                        },
-                        Handful {
-                            red: 1,
-                            green: 2,
-                            blue: 6
-                        },
-                        Handful {
-                            red: 0,
-                            green: 2,
-                            blue: 0
-                        }
-                    ]
-                },
-                Game {
-                    id: 2,
-                    handfuls: vec![
-                        Handful {
-                            red: 0,
-                            green: 2,
-                            blue: 1
-                        },
-                        Handful {
-                            red: 1,
-                            green: 3,
-                            blue: 4
-                        },
-                        Handful {
-                            red: 0,
-                            green: 1,
-                            blue: 1
-                        }
-                    ]
-                },
-                Game {
-                    id: 3,
-                    handfuls: vec![
-                        Handful {
-                            red: 20,
-                            green: 8,
-                            blue: 6
-                        },
-                        Handful {
-                            red: 4,
-                            green: 13,
-                            blue: 5
-                        },
-                        Handful {
-                            red: 1,
-                            green: 5,
-                            blue: 0
-                        }
-                    ]
-                },
-                Game {
-                    id: 4,
-                    handfuls: vec![
-                        Handful {
-                            red: 3,
-                            green: 1,
-                            blue: 6
-                        },
-                        Handful {
-                            red: 6,
-                            green: 3,
-                            blue: 0
-                        },
-                        Handful {
-                            red: 14,
-                            green: 3,
-                            blue: 15
-                        }
-                    ]
-                },
-                Game {
-                    id: 5,
-                    handfuls: vec![
-                        Handful {
-                            red: 6,
-                            green: 3,
-                            blue: 1
-                        },
-                        Handful {
-                            red: 1,
-                            green: 2,
-                            blue: 2
-                        }
-                    ]
-                }
-            ]
+            games[0].handfuls[0],
+            Handful {
+                red: 4,
+                green: 0,
+                blue: 3
+            }
         );
+        assert_eq!(games[2].handfuls.len(), 3);
+        assert_eq!(games[2].handfuls[0].red, 20);
+        assert_eq!(games[4].id, 5);
     }
 
     #[test]
@@ -291,4 +199,32 @@ mod tests {
         assert_eq!(Game::parse_game_id("Game 100: ").unwrap(), ("", 100));
         assert_eq!(Game::parse_game_id("Game 15: ").unwrap(), ("", 15));
     }
+
+    #[test]
+    fn test_parse_error() {
+        let input = concat!("Game 1: 3 blue, 4 red\n", "Game 2: 1 blue, 2 purple\n",);
+        let err = parse(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            concat!(
+                "parse error on line 2, column 15: expected end of line\n",
+                "  |\n",
+                "2 | Game 2: 1 blue, 2 purple\n",
+                "  |               ^"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_messy_input() {
+        let input = concat!(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n",
+            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n",
+        );
+        let messy = concat!(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green \r\n",
+            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+        );
+        assert_eq!(parse(messy).unwrap(), parse(input).unwrap());
+    }
 }
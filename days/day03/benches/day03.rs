@@ -0,0 +1,10 @@
+use aoc_libs::bench::bench_day;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day03::Day03;
+
+fn bench(c: &mut Criterion) {
+    bench_day::<Day03>(c, 3);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
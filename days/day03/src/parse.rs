@@ -1,5 +1,8 @@
 use std::{iter, ops::Range};
 
+use aoc_libs::error::AocError;
+use aoc_libs::parse::normalise;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct PartNumber {
     pub number: usize,
@@ -49,7 +52,9 @@ impl Symbol {
 
 pub type StructuredInput = (Vec<PartNumber>, Vec<Symbol>);
 
-pub fn parse(input: &str) -> StructuredInput {
+/// any character other than a digit or a . is a symbol, so this cant actually fail.
+pub fn parse(input: &str) -> Result<StructuredInput, AocError> {
+    let input = &normalise(input);
     let mut part_numbers = vec![];
     let mut symbols = vec![];
     for (y, line) in input.lines().enumerate() {
@@ -61,7 +66,7 @@ pub fn parse(input: &str) -> StructuredInput {
                 // this essentially 'shifts' the number left if it already exists.
                 number = number.map_or(Some(digit as usize), |n| Some(n * 10 + digit as usize))
             } else {
-                if char != '.' {
+                if char != '.' && !char.is_whitespace() {
                     symbols.push(Symbol { x, y, char })
                 }
                 // if number is not none, we must have just 'left' a number.
@@ -77,12 +82,13 @@ pub fn parse(input: &str) -> StructuredInput {
             }
         }
     }
-    (part_numbers, symbols)
+    Ok((part_numbers, symbols))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_libs::example;
 
     #[test]
     fn test_gear_ratio() {
@@ -138,7 +144,7 @@ mod tests {
                 y: 9,
             },
         ];
-        let symbols = vec![
+        let symbols = [
             Symbol {
                 x: 3,
                 y: 1,
@@ -228,7 +234,7 @@ mod tests {
                 y: 9,
             },
         ];
-        let symbols = vec![
+        let symbols = [
             Symbol {
                 x: 3,
                 y: 1,
@@ -318,7 +324,7 @@ mod tests {
                 y: 9,
             },
         ];
-        let symbols = vec![
+        let symbols = [
             Symbol {
                 x: 3,
                 y: 1,
@@ -409,7 +415,7 @@ mod tests {
                 y: 9,
             },
         ];
-        let symbols = vec![
+        let symbols = [
             Symbol {
                 x: 3,
                 y: 1,
@@ -455,106 +461,43 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let input = concat!(
-            "467..114..\n",
-            "...*......\n",
-            "..35..633.\n",
-            "......#...\n",
-            "617*......\n",
-            ".....+.58.\n",
-            "..592.....\n",
-            "......755.\n",
-            "...$.*....\n",
-            ".664.598..\n",
+        let example = example::load(3, "example").unwrap();
+        let (part_numbers, symbols) = parse(&example.input).unwrap();
+        assert_eq!(part_numbers.len(), 10);
+        assert_eq!(
+            part_numbers[0],
+            PartNumber {
+                number: 467,
+                x: 0..3,
+                y: 0
+            }
         );
         assert_eq!(
-            parse(input),
-            (
-                vec![
-                    PartNumber {
-                        number: 467,
-                        x: 0..3,
-                        y: 0
-                    },
-                    PartNumber {
-                        number: 114,
-                        x: 5..8,
-                        y: 0
-                    },
-                    PartNumber {
-                        number: 35,
-                        x: 2..4,
-                        y: 2
-                    },
-                    PartNumber {
-                        number: 633,
-                        x: 6..9,
-                        y: 2
-                    },
-                    PartNumber {
-                        number: 617,
-                        x: 0..3,
-                        y: 4
-                    },
-                    PartNumber {
-                        number: 58,
-                        x: 7..9,
-                        y: 5
-                    },
-                    PartNumber {
-                        number: 592,
-                        x: 2..5,
-                        y: 6
-                    },
-                    PartNumber {
-                        number: 755,
-                        x: 6..9,
-                        y: 7
-                    },
-                    PartNumber {
-                        number: 664,
-                        x: 1..4,
-                        y: 9
-                    },
-                    PartNumber {
-                        number: 598,
-                        x: 5..8,
-                        y: 9
-                    }
-                ],
-                vec![
-                    Symbol {
-                        x: 3,
-                        y: 1,
-                        char: '*'
-                    },
-                    Symbol {
-                        x: 6,
-                        y: 3,
-                        char: '#'
-                    },
-                    Symbol {
-                        x: 3,
-                        y: 4,
-                        char: '*'
-                    },
-                    Symbol {
-                        x: 5,
-                        y: 5,
-                        char: '+'
-                    },
-                    Symbol {
-                        x: 3,
-                        y: 8,
-                        char: '$'
-                    },
-                    Symbol {
-                        x: 5,
-                        y: 8,
-                        char: '*'
-                    }
-                ]
-            )
+            part_numbers[9],
+            PartNumber {
+                number: 598,
+                x: 5..8,
+                y: 9
+            }
         );
+        assert_eq!(symbols.len(), 6);
+        assert_eq!(
+            symbols[0],
+            Symbol {
+                x: 3,
+                y: 1,
+                char: '*'
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_messy_input() {
+        let input = concat!("467..114..\n", "...*......\n", "..35..633.\n");
+        let messy = concat!("467..114..\r\n", "...*......  \r\n", "..35..633.");
+        assert_eq!(parse(messy).unwrap(), parse(input).unwrap());
+        // a stray \r that got past normalising still isnt a symbol.
+        let (_, symbols) = parse("467..114..\r\n").unwrap();
+        assert!(symbols.is_empty());
     }
 }
@@ -0,0 +1,10 @@
+use aoc_libs::bench::bench_day;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day04::Day04;
+
+fn bench(c: &mut Criterion) {
+    bench_day::<Day04>(c, 4);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
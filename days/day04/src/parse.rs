@@ -1,40 +1,36 @@
-use std::collections::BTreeSet;
-
+use aoc_libs::bitset::BitSet;
+use aoc_libs::error::AocError;
+use aoc_libs::parse::{normalise, parse_lines, spaced_numbers, unsigned};
 use nom::{
     bytes::complete::tag,
-    multi::{many1, separated_list1},
-    sequence::{preceded, terminated},
+    character::complete::{space0, space1},
+    combinator::{map, verify},
+    sequence::{preceded, terminated, tuple},
     IResult,
 };
 
+/// every number on a card is under 100, so they all fit in a single u128.
+pub type Numbers = BitSet<1, u128>;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Card {
     pub id: usize,
-    pub winning_numbers: BTreeSet<u8>,
-    pub numbers: BTreeSet<u8>,
+    pub winning_numbers: Numbers,
+    pub numbers: Numbers,
     pub multiplier: usize,
 }
 
 impl Card {
     pub fn parse(input: &str) -> IResult<&str, Card> {
-        let mut game_id = terminated(
-            preceded(
-                preceded(tag("Card"), many1(tag(" "))),
-                nom::character::complete::u8,
-            ),
-            preceded(tag(":"), many1(tag(" "))),
-        );
-        let mut number_list = separated_list1(many1(tag(" ")), nom::character::complete::u8);
-        let seperator = preceded(tag(" |"), many1(tag(" ")));
-        let (input, id) = game_id(input)?;
-        let (input, winning_numbers) = number_list(input)?;
-        let (input, numbers) = preceded(seperator, number_list)(input)?;
-        let winning_numbers: BTreeSet<u8> = winning_numbers.into_iter().collect();
-        let numbers: BTreeSet<u8> = numbers.into_iter().collect();
+        let mut game_id = terminated(preceded(tuple((tag("Card"), space1)), unsigned), tag(":"));
+        let seperator = tuple((space0, tag("|")));
+        let (input, id): (&str, usize) = game_id(input)?;
+        let (input, winning_numbers) = Self::parse_numbers(input)?;
+        let (input, numbers) = preceded(seperator, Self::parse_numbers)(input)?;
         Ok((
             input,
             Card {
-                id: id as usize,
+                id,
                 winning_numbers,
                 numbers,
                 multiplier: 1,
@@ -42,71 +38,45 @@ impl Card {
         ))
     }
 
+    fn parse_numbers(input: &str) -> IResult<&str, Numbers> {
+        let fits = |numbers: &Vec<u8>| numbers.iter().all(|n| *n < 128);
+        map(verify(spaced_numbers, fits), |numbers| {
+            numbers.into_iter().map(usize::from).collect()
+        })(input)
+    }
+
     pub fn num_matches(&self) -> usize {
-        self.numbers.intersection(&self.winning_numbers).count()
+        (self.numbers & self.winning_numbers).len()
     }
 }
 
-pub fn parse(input: &str) -> Vec<Card> {
-    input.lines().map(|l| Card::parse(l).unwrap().1).collect()
+pub fn parse(input: &str) -> Result<Vec<Card>, AocError> {
+    let input = &normalise(input);
+    parse_lines(input, Card::parse)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_libs::example;
     use collection_literals::collection;
 
     #[test]
     fn test_parse() {
-        let input = concat!(
-            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n",
-            "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\n",
-            "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\n",
-            "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83\n",
-            "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36\n",
-            "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11\n",
-        );
+        let example = example::load(4, "example").unwrap();
+        let cards = parse(&example.input).unwrap();
+        assert_eq!(cards.len(), 6);
         assert_eq!(
-            parse(input),
-            vec![
-                Card {
-                    id: 1,
-                    winning_numbers: collection! {17, 41, 48, 83, 86},
-                    numbers: collection! {6, 9, 17, 31, 48, 53, 83, 86},
-                    multiplier: 1,
-                },
-                Card {
-                    id: 2,
-                    winning_numbers: collection! {13, 16, 20, 32, 61},
-                    numbers: collection! {17, 19, 24, 30, 32, 61, 68, 82},
-                    multiplier: 1,
-                },
-                Card {
-                    id: 3,
-                    winning_numbers: collection! {1, 21, 44, 53, 59},
-                    numbers: collection! {1, 14, 16, 21, 63, 69, 72, 82},
-                    multiplier: 1,
-                },
-                Card {
-                    id: 4,
-                    winning_numbers: collection! {41, 69, 73, 84, 92},
-                    numbers: collection! {5, 51, 54, 58, 59, 76, 83, 84},
-                    multiplier: 1,
-                },
-                Card {
-                    id: 5,
-                    winning_numbers: collection! {26, 28, 32, 83, 87},
-                    numbers: collection! {12, 22, 30, 36, 70, 82, 88, 93},
-                    multiplier: 1,
-                },
-                Card {
-                    id: 6,
-                    winning_numbers: collection! {13 , 18, 31, 56, 72},
-                    numbers: collection! {10, 11, 23, 35, 36, 67, 74, 77},
-                    multiplier: 1,
-                }
-            ]
+            cards[0],
+            Card {
+                id: 1,
+                winning_numbers: collection! {17, 41, 48, 83, 86},
+                numbers: collection! {6, 9, 17, 31, 48, 53, 83, 86},
+                multiplier: 1,
+            }
         );
+        assert_eq!(cards[5].id, 6);
+        assert_eq!(cards[5].num_matches(), 0);
     }
 
     #[test]
@@ -125,4 +95,40 @@ mod tests {
             )
         )
     }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 530\n";
+        let err = parse(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            concat!(
+                "parse error on line 1, column 47: expected a valid number\n",
+                "  |\n",
+                "1 | Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 530\n",
+                "  |                                               ^^^"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_messy_input() {
+        let input = concat!(
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n",
+            "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\n",
+        );
+        let messy = concat!(
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53   \r\n",
+            "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19",
+        );
+        assert_eq!(parse(messy).unwrap(), parse(input).unwrap());
+    }
+
+    #[test]
+    fn test_num_matches() {
+        let (_, card) = Card::parse("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        assert_eq!(card.num_matches(), 4);
+        assert!(Card::parse("Card 1: 41 48 | 83 127").is_ok());
+        assert!(Card::parse("Card 1: 41 48 | 83 128").is_err());
+    }
 }
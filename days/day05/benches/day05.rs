@@ -0,0 +1,31 @@
+use std::hint::black_box;
+
+use aoc_libs::bench::{bench_day, real_input};
+use aoc_libs::example;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day05::parse::{self, SeedRange};
+use day05::Day05;
+
+fn bench(c: &mut Criterion) {
+    bench_day::<Day05>(c, 5);
+}
+
+fn bench_map_ranges(c: &mut Criterion) {
+    let input = real_input(5).unwrap_or_else(|| example::load(5, "example").unwrap().input);
+    let (seeds, maps) = parse::parse(&input).unwrap();
+    let seeds: Vec<SeedRange> = seeds.chunks(2).map(|s| s[0]..s[0] + s[1]).collect();
+    c.bench_function("day05/map_ranges", |b| {
+        b.iter(|| {
+            maps.iter()
+                .flat_map(|map| {
+                    seeds
+                        .iter()
+                        .flat_map(|s| map.map_ranges(black_box(s.clone())))
+                })
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, bench, bench_map_ranges);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+pub mod parse;
+pub mod part1;
+pub mod part2;
+
+use aoc_libs::day::Day;
+use aoc_libs::error::AocError;
+use aoc_libs::solution::{Answer, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<u64>, Vec<parse::Map>);
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::part2(input).into()
+    }
+}
+
+pub const DAY: Day = Day::new::<Day05>(5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_libs::example;
+    use aoc_libs::solution::assert_parts_independent;
+
+    #[test]
+    fn test_parts_independent() {
+        let example = example::load(5, "example").unwrap();
+        assert_parts_independent::<Day05>(&example.input);
+    }
+}
@@ -1,10 +1,14 @@
 use std::ops::Range;
 
+use aoc_libs::error::AocError;
+use aoc_libs::parse::{
+    blank_line, key_values, lines, normalise, parse_all, sections, spaced_numbers,
+};
 use nom::{
     bytes::complete::tag,
-    character::complete::{alpha1, multispace0},
-    multi::{count, many1, separated_list1},
-    sequence::{delimited, preceded, terminated},
+    character::complete::{alpha1, line_ending},
+    combinator::map_opt,
+    sequence::{pair, preceded, separated_pair, terminated},
     IResult,
 };
 
@@ -73,13 +77,16 @@ impl Map {
         src
     }
     fn parse(input: &str) -> IResult<&str, Self> {
-        let (input, (from, to)) = delimited(multispace0, Self::parse_to_from, multispace0)(input)?;
-        let (input, ranges) = many1(MapRange::parse)(input)?;
+        let (input, (from, to)) = Self::parse_to_from(input)?;
+        let (input, ranges) = lines(MapRange::parse)(input)?;
         Ok((input, Map { from, to, ranges }))
     }
     fn parse_to_from(input: &str) -> IResult<&str, (String, String)> {
         let (input, from) = alpha1(input)?;
-        let (input, to) = terminated(preceded(tag("-to-"), alpha1), tag(" map:\n"))(input)?;
+        let (input, to) = terminated(
+            preceded(tag("-to-"), alpha1),
+            pair(tag(" map:"), line_ending),
+        )(input)?;
         Ok((input, (from.to_string(), to.to_string())))
     }
 }
@@ -110,35 +117,30 @@ impl MapRange {
     }
 
     fn parse(input: &str) -> IResult<&str, Self> {
-        let number = delimited(multispace0, nom::character::complete::u64, multispace0);
-        let (input, numbers) = count(number, 3)(input)?;
-        Ok((
-            input,
-            MapRange {
-                dest_start: numbers[0],
-                src_start: numbers[1],
-                len: numbers[2],
-            },
-        ))
+        map_opt(spaced_numbers, |numbers: Vec<u64>| match numbers[..] {
+            [dest_start, src_start, len] => Some(MapRange {
+                dest_start,
+                src_start,
+                len,
+            }),
+            _ => None,
+        })(input)
     }
 }
 
 fn parse_input(input: &str) -> IResult<&str, (Vec<u64>, Vec<Map>)> {
-    let (input, seeds) = preceded(
-        tag("seeds: "),
-        separated_list1(tag(" "), nom::character::complete::u64),
-    )(input)?;
-    let (input, maps) = many1(Map::parse)(input)?;
-    Ok((input, (seeds, maps)))
+    separated_pair(key_values("seeds"), blank_line, sections(Map::parse))(input)
 }
 
-pub fn parse(input: &str) -> (Vec<u64>, Vec<Map>) {
-    parse_input(input).unwrap().1
+pub fn parse(input: &str) -> Result<(Vec<u64>, Vec<Map>), AocError> {
+    let input = &normalise(input);
+    parse_all(input, parse_input)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_libs::example;
 
     #[test]
     fn test_map_range() {
@@ -239,7 +241,7 @@ mod tests {
         assert_eq!(
             Map::parse(input).unwrap(),
             (
-                "",
+                "\n\n",
                 Map {
                     from: "seed".to_string(),
                     to: "soil".to_string(),
@@ -265,7 +267,7 @@ mod tests {
         assert_eq!(
             MapRange::parse("50 98 2\n").unwrap(),
             (
-                "",
+                "\n",
                 MapRange {
                     dest_start: 50,
                     src_start: 98,
@@ -276,7 +278,7 @@ mod tests {
         assert_eq!(
             MapRange::parse("0 15 37\n").unwrap(),
             (
-                "",
+                "\n",
                 MapRange {
                     dest_start: 0,
                     src_start: 15,
@@ -300,70 +302,88 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
+        let example = example::load(5, "example").unwrap();
+        let (rest, (seeds, maps)) = parse_input(&example.input).unwrap();
+        // the trailing newline is left for parse_all to allow.
+        assert_eq!(rest, "\n");
+        assert_eq!(seeds, vec![79, 14, 55, 13]);
+        assert_eq!(maps.len(), 7);
+        assert_eq!(
+            (maps[1].from.as_str(), maps[1].to.as_str()),
+            ("soil", "fertilizer")
+        );
+        assert_eq!(
+            maps[1].ranges[2],
+            MapRange {
+                dest_start: 39,
+                src_start: 0,
+                len: 15
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let example = example::load(5, "example").unwrap();
+        let (seeds, maps) = parse(&example.input).unwrap();
+        assert_eq!(seeds, vec![79, 14, 55, 13]);
+        assert_eq!(maps.len(), 7);
+        assert_eq!(
+            maps[0],
+            Map {
+                from: "seed".to_string(),
+                to: "soil".to_string(),
+                ranges: vec![
+                    MapRange {
+                        dest_start: 50,
+                        src_start: 98,
+                        len: 2
+                    },
+                    MapRange {
+                        dest_start: 52,
+                        src_start: 50,
+                        len: 48
+                    },
+                ]
+            }
+        );
+        assert_eq!(
+            (maps[6].from.as_str(), maps[6].to.as_str()),
+            ("humidity", "location")
+        );
+        assert_eq!(
+            maps[6].ranges[1],
+            MapRange {
+                dest_start: 56,
+                src_start: 93,
+                len: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
         let input = concat!(
             "seeds: 79 14 55 13\n",
             "\n",
             "seed-to-soil map:\n",
             "50 98 2\n",
-            "52 50 48\n",
-            "\n",
-            "soil-to-fertilizer map:\n",
-            "0 15 37\n",
-            "37 52 2\n",
-            "39 0 15\n",
+            "52 50\n",
         );
+        let err = parse(input).unwrap_err();
         assert_eq!(
-            parse_input(input).unwrap(),
-            (
-                "",
-                (
-                    vec![79, 14, 55, 13],
-                    vec![
-                        Map {
-                            from: "seed".to_string(),
-                            to: "soil".to_string(),
-                            ranges: vec![
-                                MapRange {
-                                    dest_start: 50,
-                                    src_start: 98,
-                                    len: 2
-                                },
-                                MapRange {
-                                    dest_start: 52,
-                                    src_start: 50,
-                                    len: 48
-                                },
-                            ]
-                        },
-                        Map {
-                            from: "soil".to_string(),
-                            to: "fertilizer".to_string(),
-                            ranges: vec![
-                                MapRange {
-                                    dest_start: 0,
-                                    src_start: 15,
-                                    len: 37
-                                },
-                                MapRange {
-                                    dest_start: 37,
-                                    src_start: 52,
-                                    len: 2
-                                },
-                                MapRange {
-                                    dest_start: 39,
-                                    src_start: 0,
-                                    len: 15
-                                },
-                            ]
-                        }
-                    ]
-                )
+            err.to_string(),
+            concat!(
+                "parse error on line 5, column 1: expected end of input\n",
+                "  |\n",
+                "5 | 52 50\n",
+                "  | ^^"
             )
         );
     }
 
     #[test]
-    fn test_parse() {
+    fn test_parse_messy_input() {
         let input = concat!(
             "seeds: 79 14 55 13\n",
             "\n",
@@ -373,171 +393,21 @@ mod tests {
             "\n",
             "soil-to-fertilizer map:\n",
             "0 15 37\n",
-            "37 52 2\n",
-            "39 0 15\n",
-            "\n",
-            "fertilizer-to-water map:\n",
-            "49 53 8\n",
-            "0 11 42\n",
-            "42 0 7\n",
-            "57 7 4\n",
-            "\n",
-            "water-to-light map:\n",
-            "88 18 7\n",
-            "18 25 70\n",
-            "\n",
-            "light-to-temperature map:\n",
-            "45 77 23\n",
-            "81 45 19\n",
-            "68 64 13\n",
-            "\n",
-            "temperature-to-humidity map:\n",
-            "0 69 1\n",
-            "1 0 69\n",
-            "\n",
-            "humidity-to-location map:\n",
-            "60 56 37\n",
-            "56 93 4\n",
         );
+        let messy = concat!(
+            "seeds: 79 14 55 13 \r\n",
+            "\r\n",
+            "seed-to-soil map:  \r\n",
+            "50 98 2\r\n",
+            "52 50 48\r\n",
+            " \r\n",
+            "soil-to-fertilizer map:\r\n",
+            "0 15 37",
+        );
+        assert_eq!(parse(messy).unwrap(), parse(input).unwrap());
         assert_eq!(
-            parse(input),
-            (
-                vec![79, 14, 55, 13],
-                vec![
-                    Map {
-                        from: "seed".to_string(),
-                        to: "soil".to_string(),
-                        ranges: vec![
-                            MapRange {
-                                dest_start: 50,
-                                src_start: 98,
-                                len: 2
-                            },
-                            MapRange {
-                                dest_start: 52,
-                                src_start: 50,
-                                len: 48
-                            }
-                        ]
-                    },
-                    Map {
-                        from: "soil".to_string(),
-                        to: "fertilizer".to_string(),
-                        ranges: vec![
-                            MapRange {
-                                dest_start: 0,
-                                src_start: 15,
-                                len: 37
-                            },
-                            MapRange {
-                                dest_start: 37,
-                                src_start: 52,
-                                len: 2
-                            },
-                            MapRange {
-                                dest_start: 39,
-                                src_start: 0,
-                                len: 15
-                            }
-                        ]
-                    },
-                    Map {
-                        from: "fertilizer".to_string(),
-                        to: "water".to_string(),
-                        ranges: vec![
-                            MapRange {
-                                dest_start: 49,
-                                src_start: 53,
-                                len: 8
-                            },
-                            MapRange {
-                                dest_start: 0,
-                                src_start: 11,
-                                len: 42
-                            },
-                            MapRange {
-                                dest_start: 42,
-                                src_start: 0,
-                                len: 7
-                            },
-                            MapRange {
-                                dest_start: 57,
-                                src_start: 7,
-                                len: 4
-                            }
-                        ]
-                    },
-                    Map {
-                        from: "water".to_string(),
-                        to: "light".to_string(),
-                        ranges: vec![
-                            MapRange {
-                                dest_start: 88,
-                                src_start: 18,
-                                len: 7
-                            },
-                            MapRange {
-                                dest_start: 18,
-                                src_start: 25,
-                                len: 70
-                            }
-                        ]
-                    },
-                    Map {
-                        from: "light".to_string(),
-                        to: "temperature".to_string(),
-                        ranges: vec![
-                            MapRange {
-                                dest_start: 45,
-                                src_start: 77,
-                                len: 23
-                            },
-                            MapRange {
-                                dest_start: 81,
-                                src_start: 45,
-                                len: 19
-                            },
-                            MapRange {
-                                dest_start: 68,
-                                src_start: 64,
-                                len: 13
-                            }
-                        ]
-                    },
-                    Map {
-                        from: "temperature".to_string(),
-                        to: "humidity".to_string(),
-                        ranges: vec![
-                            MapRange {
-                                dest_start: 0,
-                                src_start: 69,
-                                len: 1
-                            },
-                            MapRange {
-                                dest_start: 1,
-                                src_start: 0,
-                                len: 69
-                            }
-                        ]
-                    },
-                    Map {
-                        from: "humidity".to_string(),
-                        to: "location".to_string(),
-                        ranges: vec![
-                            MapRange {
-                                dest_start: 60,
-                                src_start: 56,
-                                len: 37
-                            },
-                            MapRange {
-                                dest_start: 56,
-                                src_start: 93,
-                                len: 4
-                            }
-                        ]
-                    }
-                ]
-            )
+            Map::parse_to_from("seed-to-soil map:\r\n").unwrap(),
+            ("", ("seed".to_string(), "soil".to_string()))
         );
     }
 }
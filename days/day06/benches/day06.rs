@@ -0,0 +1,10 @@
+use aoc_libs::bench::bench_day;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day06::Day06;
+
+fn bench(c: &mut Criterion) {
+    bench_day::<Day06>(c, 6);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
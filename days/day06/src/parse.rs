@@ -1,10 +1,9 @@
-use nom::{
-    bytes::complete::tag,
-    character::complete::multispace0,
-    multi::separated_list1,
-    sequence::{preceded, terminated},
-    IResult,
-};
+use aoc_libs::quadratic::positive_interval;
+use std::str::FromStr;
+
+use aoc_libs::error::AocError;
+use aoc_libs::parse::{key_values, normalise, parse_all};
+use nom::{character::complete::line_ending, sequence::separated_pair};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Race {
@@ -13,86 +12,69 @@ pub struct Race {
 }
 
 impl Race {
-    // pub fn distance_given_charge_time(&self, charge_time: u64) -> u64 {
-    //     if charge_time <= self.time {
-    //         charge_time * (self.time - charge_time)
-    //     } else {
-    //         0
-    //     }
-    // }
-
     pub fn num_ways_to_win(&self) -> u64 {
-        // since distance = charge(time-charge),
-        // we can rearrange into charge^2-(time)charge + distance = 0.
-        // if we set distance to record+1 (the min distance needed to win), we can use the quadratic formula, where
-        // a=1
-        // also notice that the upper and lower bound of charge times always sums up to
-        // the total time, so we can compute the lower bound from the upper bound.
-        // (too lazy to prove this...)
-        let b = -(self.time as f64);
-        let c = (self.record + 1) as f64;
-        let upper_bound = ((-b + (b.powi(2) - 4.0 * c).sqrt()) / 2.0).floor() as u64;
-        let lower_bound = self.time - upper_bound;
-        println!(
-            "upper bound is {}, lower bound is {}",
-            upper_bound, lower_bound
-        );
-        // off by one because if your upper and lower bounds are the same, there is 1 way to win.
-        upper_bound - lower_bound + 1
+        // since distance = charge(time-charge), we win whenever
+        // -charge^2 + (time)charge - record > 0.
+        // solving that exactly over the integers gives every winning charge time.
+        let winning_charges = positive_interval(-1, self.time as i128, -(self.record as i128))
+            .expect("times and records fit in a u64, so the winning charges do too");
+        (winning_charges.end - winning_charges.start) as u64
     }
 }
 
-pub fn parse(input: &str) -> Vec<Race> {
-    let times: IResult<&str, Vec<u64>> = terminated(
-        preceded(
-            preceded(tag("Time:"), multispace0),
-            separated_list1(multispace0, nom::character::complete::u64),
-        ),
-        multispace0,
-    )(input);
-    let (input, times) = times.unwrap();
-    let distances: IResult<&str, Vec<u64>> = terminated(
-        preceded(
-            preceded(tag("Distance:"), multispace0),
-            separated_list1(multispace0, nom::character::complete::u64),
-        ),
-        multispace0,
-    )(input);
-    let (input, distances) = distances.unwrap();
-    assert_eq!(input, "");
-    times
+/// the times and distances lines, which have to have the same number of values.
+fn parse_lines<T: FromStr>(input: &str) -> Result<(Vec<T>, Vec<T>), AocError> {
+    let (times, distances) = parse_all(
+        input,
+        separated_pair(key_values("Time"), line_ending, key_values("Distance")),
+    )?;
+    if times.len() != distances.len() {
+        return Err(AocError::parse(
+            input,
+            input.find("Distance").unwrap_or(0),
+            format!("{} distances, one per time", times.len()),
+        ));
+    }
+    Ok((times, distances))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Race>, AocError> {
+    let input = &normalise(input);
+    let (times, distances) = parse_lines::<u64>(input)?;
+    Ok(times
         .into_iter()
         .zip(distances)
         .map(|r| Race {
             time: r.0,
             record: r.1,
         })
-        .collect()
+        .collect())
 }
 
-pub fn part2_parse(input: &str) -> Race {
-    let mut string = input.to_string();
-    string.retain(|c| c != ' ');
-    let time: IResult<&str, u64> = terminated(
-        preceded(tag("Time:"), nom::character::complete::u64),
-        multispace0,
-    )(&string);
-    let (input, time) = time.unwrap();
-    let distance: IResult<&str, u64> = terminated(
-        preceded(tag("Distance:"), nom::character::complete::u64),
-        multispace0,
-    )(input);
-    let (input, distance) = distance.unwrap();
-    assert_eq!(input, "");
-    Race {
-        time,
-        record: distance,
-    }
+/// reads the sheet as a single race, so part 2 can share part 1s input type.
+pub fn part2_parse(input: &str) -> Result<Vec<Race>, AocError> {
+    let input = &normalise(input);
+    // the numbers are really one big number with bad kerning, so stick their digits together.
+    let (times, distances) = parse_lines::<String>(input)?;
+    let join = |numbers: Vec<String>, line: &str| {
+        numbers.concat().parse().map_err(|_| {
+            AocError::parse(
+                input,
+                input.find(line).unwrap_or(0),
+                "a number that fits in a u64",
+            )
+        })
+    };
+    Ok(vec![Race {
+        time: join(times, "Time")?,
+        record: join(distances, "Distance")?,
+    }])
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_libs::example;
 
     #[test]
     fn test_num_ways_to_win() {
@@ -115,34 +97,77 @@ mod tests {
         assert_eq!(input.num_ways_to_win(), 71503);
     }
 
+    #[test]
+    fn test_num_ways_to_win_impossible() {
+        let input = Race { time: 2, record: 5 };
+        assert_eq!(input.num_ways_to_win(), 0);
+        let input = Race { time: 4, record: 4 };
+        assert_eq!(input.num_ways_to_win(), 0);
+    }
+
+    #[test]
+    fn test_num_ways_to_win_u64_max() {
+        let input = Race {
+            time: u64::MAX,
+            record: 0,
+        };
+        assert_eq!(input.num_ways_to_win(), u64::MAX - 1);
+        let input = Race {
+            time: u64::MAX,
+            record: u64::MAX,
+        };
+        assert_eq!(input.num_ways_to_win(), u64::MAX - 3);
+    }
+
     #[test]
     fn test_parse_part2() {
-        let input = concat!("Time:      7  15   30\n", "Distance:  9  40  200\n",);
+        let example = example::load(6, "example").unwrap();
         assert_eq!(
-            part2_parse(input),
-            Race {
+            part2_parse(&example.input).unwrap(),
+            vec![Race {
                 time: 71530,
                 record: 940200
-            }
+            }]
         );
     }
 
     #[test]
     fn test_parse() {
-        let input = concat!("Time:      7  15   30\n", "Distance:  9  40  200\n",);
+        let example = example::load(6, "example").unwrap();
+        let races = parse(&example.input).unwrap();
+        assert_eq!(races.len(), 3);
+        assert_eq!(races[0], Race { time: 7, record: 9 });
+        assert_eq!(
+            races[2],
+            Race {
+                time: 30,
+                record: 200
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = concat!("Time:      7  15   30\n", "Distance:  9  40\n",);
+        let err = parse(input).unwrap_err();
         assert_eq!(
-            parse(input),
-            vec![
-                Race { time: 7, record: 9 },
-                Race {
-                    time: 15,
-                    record: 40
-                },
-                Race {
-                    time: 30,
-                    record: 200
-                },
-            ]
+            err.to_string(),
+            concat!(
+                "parse error on line 2, column 1: expected 3 distances, one per time\n",
+                "  |\n",
+                "2 | Distance:  9  40\n",
+                "  | ^^^^^^^^^"
+            )
         );
+        let input = concat!("Time: 7 99999999999999999999\n", "Distance:  9  40\n",);
+        assert!(part2_parse(input).is_err());
+    }
+
+    #[test]
+    fn test_parse_messy_input() {
+        let input = concat!("Time:      7  15   30\n", "Distance:  9  40  200\n",);
+        let messy = concat!("Time:      7  15   30  \r\n", "Distance:  9  40  200");
+        assert_eq!(parse(messy).unwrap(), parse(input).unwrap());
+        assert_eq!(part2_parse(messy).unwrap(), part2_parse(input).unwrap());
     }
 }
@@ -0,0 +1,10 @@
+use aoc_libs::bench::bench_day;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day07::Day07;
+
+fn bench(c: &mut Criterion) {
+    bench_day::<Day07>(c, 7);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -1,20 +1,38 @@
+use aoc_derive::CharEnum;
+use aoc_libs::error::AocError;
+use aoc_libs::parse::normalise;
 use itertools::Itertools;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, CharEnum)]
 pub enum Card {
+    #[ch('A')]
     Ace = 14,
+    #[ch('K')]
     King = 13,
+    #[ch('Q')]
     Queen = 12,
+    #[ch('J')]
     Jack = 11,
+    #[ch('T')]
     Tim = 10,
+    #[ch('9')]
     Nine = 9,
+    #[ch('8')]
     Eight = 8,
+    #[ch('7')]
     Seven = 7,
+    #[ch('6')]
     Six = 6,
+    #[ch('5')]
     Five = 5,
+    #[ch('4')]
     Four = 4,
+    #[ch('3')]
     Three = 3,
+    #[ch('2')]
     Two = 2,
+    // jokers are only ever made out of jacks, so a J in the input is always a jack.
+    #[ch('J', no_parse)]
     Joker = 1,
 }
 
@@ -29,7 +47,7 @@ pub enum Formation {
     HighCard = 1,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub struct Hand {
     formation: Formation,
     cards: [Card; 5],
@@ -73,51 +91,52 @@ impl Hand {
             _ => panic!("how are there more than 5 types!"),
         }
     }
-    pub fn turn_jacks_to_jokers(&mut self) {
-        self.cards = self
-            .cards
-            .map(|c| if c == Card::Jack { Card::Joker } else { c });
+    /// the same hand with every jack played as a joker instead. The formation is worked out
+    /// again, since jokers can make a hand stronger.
+    pub fn with_jacks_as_jokers(&self) -> Hand {
+        Hand::new(
+            self.cards
+                .map(|c| if c == Card::Jack { Card::Joker } else { c }),
+        )
     }
 }
 
-pub fn parse(input: &str) -> Vec<(Hand, u32)> {
-    input
-        .lines()
-        .map(|line| {
-            let mut splits = line.split(' ');
-            let hand = splits.next().unwrap();
-            let bid: u32 = splits.next().unwrap().parse().unwrap();
-            let hand: Vec<Card> = hand
-                .chars()
-                .map(|c| match c {
-                    'A' => Card::Ace,
-                    'K' => Card::King,
-                    'Q' => Card::Queen,
-                    'J' => Card::Jack,
-                    'T' => Card::Tim,
-                    '9' => Card::Nine,
-                    '8' => Card::Eight,
-                    '7' => Card::Seven,
-                    '6' => Card::Six,
-                    '5' => Card::Five,
-                    '4' => Card::Four,
-                    '3' => Card::Three,
-                    '2' => Card::Two,
-                    e => panic!("invalid card {}", e),
-                })
-                .collect();
-            (Hand::new(hand.try_into().unwrap()), bid)
-        })
-        .collect()
+pub fn parse(input: &str) -> Result<Vec<(Hand, u32)>, AocError> {
+    let input = &normalise(input);
+    input.lines().map(|line| parse_line(input, line)).collect()
+}
+
+fn parse_line(input: &str, line: &str) -> Result<(Hand, u32), AocError> {
+    let Some((hand, bid)) = line.split_once(' ') else {
+        return Err(AocError::parse_at(
+            input,
+            &line[line.len()..],
+            "a space then a bid",
+        ));
+    };
+    let bid: u32 = bid
+        .parse()
+        .map_err(|_| AocError::parse_at(input, bid, "a bid"))?;
+    let mut cards = Vec::new();
+    for (i, c) in hand.char_indices() {
+        let card =
+            Card::try_from(c).map_err(|_| AocError::parse_at(input, &hand[i..], "a card"))?;
+        cards.push(card);
+    }
+    let cards = cards
+        .try_into()
+        .map_err(|_| AocError::parse_at(input, hand, "a hand of 5 cards"))?;
+    Ok((Hand::new(cards), bid))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_libs::example;
 
     #[test]
     fn test_ord_with_joker_conversion() {
-        let mut input = vec![
+        let input = vec![
             (
                 Hand::new([Card::Two, Card::Three, Card::Four, Card::Five, Card::Ace]),
                 1,
@@ -225,7 +244,10 @@ mod tests {
                 41,
             ),
         ];
-        input.iter_mut().for_each(|i| i.0.turn_jacks_to_jokers());
+        let mut input: Vec<_> = input
+            .iter()
+            .map(|i| (i.0.with_jacks_as_jokers(), i.1))
+            .collect();
         input.sort_by(|a, b| a.0.cmp(&b.0));
         println!("{:#?}", input);
         //check that the bids are sorted (the input is curated to ensure that the bids are sorted
@@ -235,7 +257,7 @@ mod tests {
 
     #[test]
     fn test_determine_formation_after_joker_conversion() {
-        let mut tests = vec![
+        let tests = vec![
             (
                 Hand::new([Card::Two, Card::Three, Card::Four, Card::Five, Card::Ace]),
                 Formation::HighCard,
@@ -343,12 +365,22 @@ mod tests {
                 Formation::FiveOfKind,
             ),
         ];
-        tests.iter_mut().for_each(|i| i.0.turn_jacks_to_jokers());
         for test in tests {
-            assert_eq!(test.0.formation, test.1)
+            assert_eq!(test.0.with_jacks_as_jokers().formation, test.1)
         }
     }
 
+    #[test]
+    fn test_jokers_change_formation() {
+        let hand = Hand::new([Card::Queen, Card::Queen, Card::Queen, Card::Jack, Card::Ace]);
+        assert_eq!(hand.formation, Formation::ThreeOfKind);
+        let jokers = hand.with_jacks_as_jokers();
+        assert_eq!(jokers.formation, Formation::FourOfKind);
+        assert_eq!(jokers.cards[3], Card::Joker);
+        // the original hand is left alone.
+        assert_eq!(hand.cards[3], Card::Jack);
+    }
+
     #[test]
     fn test_ord() {
         let mut input = vec![
@@ -536,113 +568,47 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let input = concat!(
-            "2345A 1\n",
-            "Q2KJJ 13\n",
-            "Q2Q2Q 19\n",
-            "T3T3J 17\n",
-            "T3Q33 11\n",
-            "2345J 3\n",
-            "J345A 2\n",
-            "32T3K 5\n",
-            "T55J5 29\n",
-            "KK677 7\n",
-            "KTJJT 34\n",
-            "QQQJA 31\n",
-            "JJJJJ 37\n",
-            "JAAAA 43\n",
-            "AAAAJ 59\n",
-            "AAAAA 61\n",
-            "2AAAA 23\n",
-            "2JJJJ 53\n",
-            "JJJJ2 41\n",
+        let example = example::load(7, "more_hands").unwrap();
+        let hands = parse(&example.input).unwrap();
+        assert_eq!(hands.len(), 19);
+        assert_eq!(
+            hands[0],
+            (
+                Hand::new([Card::Two, Card::Three, Card::Four, Card::Five, Card::Ace]),
+                1
+            )
         );
         assert_eq!(
-            parse(input),
-            vec![
-                (
-                    Hand::new([Card::Two, Card::Three, Card::Four, Card::Five, Card::Ace]),
-                    1
-                ),
-                (
-                    Hand::new([Card::Queen, Card::Two, Card::King, Card::Jack, Card::Jack]),
-                    13
-                ),
-                (
-                    Hand::new([Card::Queen, Card::Two, Card::Queen, Card::Two, Card::Queen]),
-                    19
-                ),
-                (
-                    Hand::new([Card::Tim, Card::Three, Card::Tim, Card::Three, Card::Jack]),
-                    17
-                ),
-                (
-                    Hand::new([
-                        Card::Tim,
-                        Card::Three,
-                        Card::Queen,
-                        Card::Three,
-                        Card::Three
-                    ]),
-                    11
-                ),
-                (
-                    Hand::new([Card::Two, Card::Three, Card::Four, Card::Five, Card::Jack]),
-                    3
-                ),
-                (
-                    Hand::new([Card::Jack, Card::Three, Card::Four, Card::Five, Card::Ace]),
-                    2
-                ),
-                (
-                    Hand::new([Card::Three, Card::Two, Card::Tim, Card::Three, Card::King]),
-                    5
-                ),
-                (
-                    Hand::new([Card::Tim, Card::Five, Card::Five, Card::Jack, Card::Five]),
-                    29
-                ),
-                (
-                    Hand::new([Card::King, Card::King, Card::Six, Card::Seven, Card::Seven]),
-                    7
-                ),
-                (
-                    Hand::new([Card::King, Card::Tim, Card::Jack, Card::Jack, Card::Tim]),
-                    34
-                ),
-                (
-                    Hand::new([Card::Queen, Card::Queen, Card::Queen, Card::Jack, Card::Ace]),
-                    31
-                ),
-                (
-                    Hand::new([Card::Jack, Card::Jack, Card::Jack, Card::Jack, Card::Jack]),
-                    37
-                ),
-                (
-                    Hand::new([Card::Jack, Card::Ace, Card::Ace, Card::Ace, Card::Ace]),
-                    43
-                ),
-                (
-                    Hand::new([Card::Ace, Card::Ace, Card::Ace, Card::Ace, Card::Jack]),
-                    59
-                ),
-                (
-                    Hand::new([Card::Ace, Card::Ace, Card::Ace, Card::Ace, Card::Ace]),
-                    61
-                ),
-                (
-                    Hand::new([Card::Two, Card::Ace, Card::Ace, Card::Ace, Card::Ace]),
-                    23
-                ),
-                (
-                    Hand::new([Card::Two, Card::Jack, Card::Jack, Card::Jack, Card::Jack]),
-                    53
-                ),
-                (
-                    Hand::new([Card::Jack, Card::Jack, Card::Jack, Card::Jack, Card::Two]),
-                    41
-                )
-            ]
+            hands[18],
+            (
+                Hand::new([Card::Jack, Card::Jack, Card::Jack, Card::Jack, Card::Two]),
+                41
+            )
         );
     }
+
+    #[test]
+    fn test_parse_error() {
+        let input = concat!("32T3K 765\n", "T55X5 684\n");
+        let err = parse(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            concat!(
+                "parse error on line 2, column 4: expected a card\n",
+                "  |\n",
+                "2 | T55X5 684\n",
+                "  |    ^^"
+            )
+        );
+        assert!(parse("32T3 765\n").is_err());
+        assert!(parse("32T3K\n").is_err());
+        assert!(parse("32T3K -765\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_messy_input() {
+        let input = concat!("32T3K 765\n", "T55J5 684\n", "KK677 28\n");
+        let messy = concat!("32T3K 765\r\n", "T55J5 684 \r\n", "KK677 28\r\n\r\n");
+        assert_eq!(parse(messy).unwrap(), parse(input).unwrap());
+    }
 }
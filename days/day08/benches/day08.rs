@@ -0,0 +1,10 @@
+use aoc_libs::bench::bench_day;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day08::Day08;
+
+fn bench(c: &mut Criterion) {
+    bench_day::<Day08>(c, 8);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -1,61 +1,100 @@
-use std::collections::HashMap;
-
+use aoc_derive::CharEnum;
+use aoc_libs::error::AocError;
+use aoc_libs::graph::Graph;
+use aoc_libs::parse::normalise;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, CharEnum)]
 pub enum Direction {
+    #[ch('L')]
     Left,
+    #[ch('R')]
     Right,
 }
 
-#[derive(Debug, Hash, PartialEq, Eq)]
-pub struct Node {
-    pub left: String,
-    pub right: String,
-}
+pub type Network = Graph<String, Direction>;
 
 static NODE_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"^([[:alnum:]]{3}) = \(([[:alnum:]]{3}), ([[:alnum:]]{3})\)$").unwrap()
 });
 
-pub fn parse(input: &str) -> (Vec<Direction>, HashMap<String, Node>) {
+pub fn parse(input: &str) -> Result<(Vec<Direction>, Network), AocError> {
+    let input = &normalise(input);
     let mut lines = input.lines();
     let mut directions = Vec::new();
     //parse the directions
-    let dirline = lines.next().unwrap();
-    for char in dirline.chars() {
-        directions.push(match char {
-            'L' => Direction::Left,
-            'R' => Direction::Right,
-            e => panic!("unexpected direction {}", e),
-        })
+    let dirline = lines.next().unwrap_or(input);
+    for (i, char) in dirline.char_indices() {
+        directions.push(
+            Direction::try_from(char)
+                .map_err(|_| AocError::parse_at(input, &dirline[i..], "L or R"))?,
+        )
+    }
+    if directions.is_empty() {
+        return Err(AocError::parse_at(input, dirline, "a list of directions"));
     }
     //skip a blank line
-    lines.next();
+    if let Some(line) = lines.next().filter(|l| !l.is_empty()) {
+        return Err(AocError::parse_at(input, line, "a blank line"));
+    }
 
     // process the rest of the lines
-    let mut graph = HashMap::new();
+    let mut graph = Graph::new();
     for line in lines {
-        let captures = NODE_REGEX.captures(line).unwrap();
-        graph.insert(
-            captures[1].to_string(),
-            Node {
-                left: captures[2].to_string(),
-                right: captures[3].to_string(),
-            },
-        );
+        let captures = NODE_REGEX
+            .captures(line)
+            .ok_or_else(|| AocError::parse_at(input, line, "a node like AAA = (BBB, CCC)"))?;
+        graph.add_named_edge(&captures[1], &captures[2], Direction::Left);
+        graph.add_named_edge(&captures[1], &captures[3], Direction::Right);
     }
-    (directions, graph)
+    Ok((directions, graph))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use collection_literals::collection;
+    use aoc_libs::example;
 
     #[test]
     fn test_parse() {
+        let example = example::load(8, "example").unwrap();
+        let (directions, graph) = parse(&example.input).unwrap();
+        assert_eq!(
+            directions,
+            vec![Direction::Left, Direction::Left, Direction::Right]
+        );
+        assert_eq!(graph.len(), 3);
+        let follow = |from: &str, direction: Direction| {
+            graph.name(graph.follow(graph.id(from).unwrap(), &direction).unwrap())
+        };
+        assert_eq!(follow("AAA", Direction::Left), "BBB");
+        assert_eq!(follow("AAA", Direction::Right), "BBB");
+        assert_eq!(follow("BBB", Direction::Left), "AAA");
+        assert_eq!(follow("BBB", Direction::Right), "ZZZ");
+        assert_eq!(follow("ZZZ", Direction::Left), "ZZZ");
+        assert_eq!(follow("ZZZ", Direction::Right), "ZZZ");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = concat!("LLR\n", "\n", "AAA = (BBB, BBB)\n", "BBB = AAA, ZZZ\n",);
+        let err = parse(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            concat!(
+                "parse error on line 4, column 1: expected a node like AAA = (BBB, CCC)\n",
+                "  |\n",
+                "4 | BBB = AAA, ZZZ\n",
+                "  | ^^^"
+            )
+        );
+        assert!(parse("LXR\n\nAAA = (BBB, BBB)\n").is_err());
+        assert!(parse("LLR\nAAA = (BBB, BBB)\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_messy_input() {
         let input = concat!(
             "LLR\n",
             "\n",
@@ -63,17 +102,13 @@ mod tests {
             "BBB = (AAA, ZZZ)\n",
             "ZZZ = (ZZZ, ZZZ)\n",
         );
-        println!("{:#?}", parse(input));
-        assert_eq!(
-            parse(input),
-            (
-                vec![Direction::Left, Direction::Left, Direction::Right],
-                collection! {
-                "AAA".to_string() => Node{ left: "BBB".to_string(), right: "BBB".to_string() },
-                "BBB".to_string() => Node{ left: "AAA".to_string(), right: "ZZZ".to_string() },
-                "ZZZ".to_string() => Node{ left: "ZZZ".to_string(), right: "ZZZ".to_string() },
-                }
-            )
+        let messy = concat!(
+            "LLR  \r\n",
+            "\r\n",
+            "AAA = (BBB, BBB)\r\n",
+            "BBB = (AAA, ZZZ) \r\n",
+            "ZZZ = (ZZZ, ZZZ)",
         );
+        assert_eq!(parse(messy).unwrap(), parse(input).unwrap());
     }
 }
@@ -1,8 +1,9 @@
-pub fn parse(input: &str) -> Vec<Vec<i32>> {
-    input
-        .lines()
-        .map(|l| l.split(' ').map(|n| n.parse().unwrap()).collect())
-        .collect()
+use aoc_libs::error::AocError;
+use aoc_libs::parse::{normalise, parse_lines, spaced_numbers};
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i32>>, AocError> {
+    let input = &normalise(input);
+    parse_lines(input, spaced_numbers)
 }
 
 pub fn get_differences(input: &[i32]) -> Vec<i32> {
@@ -20,6 +21,7 @@ pub fn extrapolate_sequence(input: &[i32]) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_libs::example;
 
     #[test]
     fn test_get_differences() {
@@ -35,14 +37,32 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let input = concat!("0 3 6 9 12 15\n", "1 3 6 10 15 21\n", "10 13 16 21 30 45\n",);
+        let example = example::load(9, "example").unwrap();
+        let sequences = parse(&example.input).unwrap();
+        assert_eq!(sequences.len(), 3);
+        assert_eq!(sequences[0], vec![0, 3, 6, 9, 12, 15]);
+        assert_eq!(sequences[2], vec![10, 13, 16, 21, 30, 45]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = concat!("0 3 6 9 12 15\n", "1 3 6 1O 15 21\n");
+        let err = parse(input).unwrap_err();
         assert_eq!(
-            parse(input),
-            vec![
-                vec![0, 3, 6, 9, 12, 15],
-                vec![1, 3, 6, 10, 15, 21],
-                vec![10, 13, 16, 21, 30, 45],
-            ]
+            err.to_string(),
+            concat!(
+                "parse error on line 2, column 8: expected end of line\n",
+                "  |\n",
+                "2 | 1 3 6 1O 15 21\n",
+                "  |        ^"
+            )
         );
     }
+
+    #[test]
+    fn test_parse_messy_input() {
+        let input = concat!("0 3 6 9 12 15\n", "1 3 6 10 15 21\n");
+        let messy = concat!("0 3 6 9 12 15 \r\n", "1 3 6 10 15 21");
+        assert_eq!(parse(messy).unwrap(), parse(input).unwrap());
+    }
 }
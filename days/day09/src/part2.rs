@@ -1,26 +1,11 @@
 use crate::parse::*;
 
-pub fn part2(input: &mut [Vec<i32>]) -> i32 {
+pub fn part2(input: &[Vec<i32>]) -> i32 {
     input
-        .iter_mut()
+        .iter()
         .map(|l| {
-            l.reverse();
-            extrapolate_sequence(l)
+            let reversed: Vec<i32> = l.iter().rev().copied().collect();
+            extrapolate_sequence(&reversed)
         })
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part2() {
-        let mut input = vec![
-            vec![0, 3, 6, 9, 12, 15],
-            vec![1, 3, 6, 10, 15, 21],
-            vec![10, 13, 16, 21, 30, 45],
-        ];
-        assert_eq!(part2(&mut input), 2);
-    }
-}
@@ -0,0 +1,22 @@
+use std::hint::black_box;
+
+use aoc_libs::bench::{bench_day, real_input};
+use aoc_libs::example;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day11::parse;
+use day11::Day11;
+
+fn bench(c: &mut Criterion) {
+    bench_day::<Day11>(c, 11);
+}
+
+fn bench_sum_of_distances(c: &mut Criterion) {
+    let input = real_input(11).unwrap_or_else(|| example::load(11, "example").unwrap().input);
+    let space = parse::parse(&input).unwrap();
+    c.bench_function("day11/get_sum_of_distances", |b| {
+        b.iter(|| space.get_sum_of_distances(black_box(1_000_000)))
+    });
+}
+
+criterion_group!(benches, bench, bench_sum_of_distances);
+criterion_main!(benches);
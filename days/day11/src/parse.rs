@@ -1,12 +1,17 @@
 use std::collections::HashSet;
 
+use aoc_derive::CharEnum;
 use aoc_libs::distances::Distances;
+use aoc_libs::error::AocError;
+use aoc_libs::parse::normalise;
 use aoc_libs::points::UPoint;
 use itertools::Itertools;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, CharEnum)]
 pub enum Space {
+    #[ch('#')]
     Galaxy,
+    #[ch('.')]
     Space,
 }
 
@@ -81,8 +86,8 @@ impl From<Vec<Vec<Space>>> for SparseSpace {
         //find all empty columns
         for x in 0..value[0].len() {
             let mut has_galaxy = false;
-            for y in 0..value.len() {
-                if value[y][x] == Space::Galaxy {
+            for row in value.iter() {
+                if row[x] == Space::Galaxy {
                     has_galaxy = true;
                 }
             }
@@ -99,23 +104,36 @@ impl From<Vec<Vec<Space>>> for SparseSpace {
     }
 }
 
-pub fn parse(input: &str) -> SparseSpace {
-    parse_to_space(input).into()
+pub fn parse(input: &str) -> Result<SparseSpace, AocError> {
+    Ok(parse_to_space(input)?.into())
 }
 
-pub fn parse_to_space(input: &str) -> Vec<Vec<Space>> {
-    input
-        .lines()
-        .map(|l| {
-            l.chars()
-                .map(|c| match c {
-                    '#' => Space::Galaxy,
-                    '.' => Space::Space,
-                    _ => panic!("unexpected char {}", c),
-                })
-                .collect()
-        })
-        .collect()
+pub fn parse_to_space(input: &str) -> Result<Vec<Vec<Space>>, AocError> {
+    let input = &normalise(input);
+    let mut space: Vec<Vec<Space>> = Vec::new();
+    for l in input.lines() {
+        let mut row = Vec::new();
+        for (x, c) in l.char_indices() {
+            let cell =
+                Space::try_from(c).map_err(|_| AocError::parse_at(input, &l[x..], "# or ."))?;
+            row.push(cell);
+        }
+        // the image has to be a rectangle, or working out the empty columns makes no sense.
+        if let Some(width) = space.first().map(|r| r.len()) {
+            if row.len() != width {
+                return Err(AocError::parse_at(
+                    input,
+                    l,
+                    format!("a row {} wide, like the first one", width),
+                ));
+            }
+        }
+        space.push(row);
+    }
+    if space.is_empty() {
+        return Err(AocError::parse(input, 0, "at least one row"));
+    }
+    Ok(space)
 }
 
 #[cfg(test)]
@@ -123,6 +141,7 @@ mod tests {
     use collection_literals::collection;
 
     use super::*;
+    use aoc_libs::example;
 
     #[test]
     fn test_get_sum_of_distances() {
@@ -213,176 +232,48 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let input = concat!(
-            "...#......\n",
-            ".......#..\n",
-            "#.........\n",
-            "..........\n",
-            "......#...\n",
-            ".#........\n",
-            ".........#\n",
-            "..........\n",
-            ".......#..\n",
-            "#...#.....\n",
-        );
-        assert_eq!(
-            parse(input),
-            SparseSpace {
-                galaxies: collection! {
-                    UPoint { x: 0, y: 2 },
-                    UPoint { x: 0, y: 9 },
-                    UPoint { x: 1, y: 5 },
-                    UPoint { x: 3, y: 0 },
-                    UPoint { x: 4, y: 9 },
-                    UPoint { x: 6, y: 4 },
-                    UPoint { x: 7, y: 1 },
-                    UPoint { x: 7, y: 8 },
-                    UPoint { x: 9, y: 6 }
-                },
-                empty_rows: collection! {3, 7},
-                empty_columns: collection! {2, 5, 8}
-            }
-        )
+        let example = example::load(11, "example").unwrap();
+        let space = parse(&example.input).unwrap();
+        assert_eq!(space.galaxies.len(), 9);
+        assert!(space.galaxies.contains(&UPoint { x: 3, y: 0 }));
+        assert!(space.galaxies.contains(&UPoint { x: 4, y: 9 }));
+        assert_eq!(space.empty_rows, collection! {3, 7});
+        assert_eq!(space.empty_columns, collection! {2, 5, 8});
     }
 
     #[test]
     fn test_parse_to_space() {
-        let input = concat!(
-            "...#......\n",
-            ".......#..\n",
-            "#.........\n",
-            "..........\n",
-            "......#...\n",
-            ".#........\n",
-            ".........#\n",
-            "..........\n",
-            ".......#..\n",
-            "#...#.....\n",
-        );
+        let example = example::load(11, "example").unwrap();
+        let space = parse_to_space(&example.input).unwrap();
+        assert_eq!(space.len(), 10);
+        assert!(space.iter().all(|row| row.len() == 10));
+        assert_eq!(space[0][3], Space::Galaxy);
+        assert_eq!(space[0][4], Space::Space);
+        let galaxies = space.iter().flatten().filter(|s| **s == Space::Galaxy);
+        assert_eq!(galaxies.count(), 9);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = concat!("...#......\n", ".......#..\n", "#...o.....\n",);
+        let err = parse(input).unwrap_err();
         assert_eq!(
-            parse_to_space(input),
-            vec![
-                vec![
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Galaxy,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space
-                ],
-                vec![
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Galaxy,
-                    Space::Space,
-                    Space::Space
-                ],
-                vec![
-                    Space::Galaxy,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space
-                ],
-                vec![
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space
-                ],
-                vec![
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Galaxy,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space
-                ],
-                vec![
-                    Space::Space,
-                    Space::Galaxy,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space
-                ],
-                vec![
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Galaxy
-                ],
-                vec![
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space
-                ],
-                vec![
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Galaxy,
-                    Space::Space,
-                    Space::Space
-                ],
-                vec![
-                    Space::Galaxy,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Galaxy,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space,
-                    Space::Space
-                ]
-            ]
+            err.to_string(),
+            concat!(
+                "parse error on line 3, column 5: expected # or .\n",
+                "  |\n",
+                "3 | #...o.....\n",
+                "  |     ^^^^^^"
+            )
         );
+        assert!(parse("...#\n..\n").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn test_parse_messy_input() {
+        let input = concat!("...#......\n", ".......#..\n", "#.........\n");
+        let messy = concat!("...#......\r\n", ".......#..  \r\n", "#.........");
+        assert_eq!(parse(messy).unwrap(), parse(input).unwrap());
     }
 }
@@ -22,7 +22,7 @@ mod tests {
             ".......#..\n",
             "#...#.....\n",
         );
-        assert_eq!(parse(input).get_sum_of_distances(9), 1030);
-        assert_eq!(parse(input).get_sum_of_distances(99), 8410);
+        assert_eq!(parse(input).unwrap().get_sum_of_distances(9), 1030);
+        assert_eq!(parse(input).unwrap().get_sum_of_distances(99), 8410);
     }
 }
@@ -0,0 +1,10 @@
+use aoc_libs::bench::bench_day;
+use criterion::{criterion_group, criterion_main, Criterion};
+use template::{Template, DAY};
+
+fn bench(c: &mut Criterion) {
+    bench_day::<Template>(c, DAY.number);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
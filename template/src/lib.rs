@@ -0,0 +1,48 @@
+// the stubs below are meant to be filled in, so dont complain about them until they are.
+#![allow(unused)]
+
+pub mod parse;
+pub mod part1;
+pub mod part2;
+
+use aoc_libs::day::Day;
+use aoc_libs::error::AocError;
+use aoc_libs::solution::{Answer, Solution};
+
+pub struct Template;
+
+impl Solution for Template {
+    type Input = parse::StructuredInput;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1::part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2::part2(input).into()
+    }
+}
+
+pub const DAY: Day = Day::new::<Template>(__DAY__);
+
+const __DAY__: u8 = 0; // stands in for the days number, aoc new fills it in and drops this line
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_libs::example;
+    use aoc_libs::solution::assert_parts_independent;
+
+    // fill in the input in examples/, then take the ignore off. The answers there are checked
+    // by the tests aoc generates for every example.
+    #[test]
+    #[ignore]
+    fn test_parts_independent() {
+        let example = example::load(DAY.number, "example").unwrap();
+        assert_parts_independent::<Template>(&example.input);
+    }
+}
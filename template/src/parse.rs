@@ -1,16 +1,9 @@
+use aoc_libs::error::AocError;
+use aoc_libs::parse::normalise;
+
 pub type StructuredInput = usize;
 
-pub fn parse(input: &str) -> StructuredInput {
+pub fn parse(input: &str) -> Result<StructuredInput, AocError> {
+    let input = &normalise(input);
     unimplemented!()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse() {
-        let input = concat!();
-        assert_eq!(0, 0);
-    }
-}
@@ -3,13 +3,3 @@ use crate::parse::*;
 pub fn part1(input: &StructuredInput) -> usize {
     unimplemented!()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(0, 0);
-    }
-}
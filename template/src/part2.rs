@@ -3,13 +3,3 @@ use crate::parse::*;
 pub fn part2(input: &StructuredInput) -> usize {
     unimplemented!()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(0, 0);
-    }
-}